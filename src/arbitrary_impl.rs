@@ -0,0 +1,56 @@
+use {
+    crate::*,
+    alloc::string::String,
+    arbitrary::{Arbitrary, Error, Result, Unstructured},
+};
+
+/// Always generates a non-empty string -
+/// if the generated [`String`] is empty, an arbitrary char is appended to it.
+impl<'a> Arbitrary<'a> for NonEmptyString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut s = String::arbitrary(u)?;
+
+        if s.is_empty() {
+            s.push(char::arbitrary(u)?);
+        }
+
+        Ok(unsafe { NonEmptyString::new_unchecked(s) })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <String as Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Fails with [`Error::IncorrectFormat`] if the generated string slice is empty,
+/// as a borrowed string slice cannot be extended.
+impl<'a> Arbitrary<'a> for &'a NonEmptyStr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmptyStr::new(<&'a str>::arbitrary(u)?).ok_or(Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&'a str as Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary() {
+        let data: [&[u8]; 5] = [&[], &[0], &[0, 0, 0, 0], b"foo bar baz", &[0xff; 64]];
+
+        for data in data {
+            let mut u = Unstructured::new(data);
+            let s = NonEmptyString::arbitrary(&mut u).unwrap();
+            assert!(!s.as_str().is_empty());
+
+            let mut u = Unstructured::new(data);
+            if let Ok(s) = <&NonEmptyStr>::arbitrary(&mut u) {
+                assert!(!s.as_str().is_empty());
+            }
+        }
+    }
+}
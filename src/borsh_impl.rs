@@ -0,0 +1,44 @@
+use {
+    crate::*,
+    alloc::string::String,
+    borsh::{
+        io::{Error, ErrorKind, Read, Result, Write},
+        BorshDeserialize, BorshSerialize,
+    },
+};
+
+/// Uses the same wire format as a [`String`].
+impl BorshSerialize for NonEmptyString {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.inner().serialize(writer)
+    }
+}
+
+/// Uses the same wire format as a [`String`].
+/// Returns an [`ErrorKind::InvalidData`] error if the deserialized string is empty.
+impl BorshDeserialize for NonEmptyString {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        NonEmptyString::new(String::deserialize_reader(reader)?)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "string is empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_deserialize() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let data = borsh::to_vec(&foo).unwrap();
+        assert_eq!(data, borsh::to_vec(&"foo".to_owned()).unwrap());
+
+        let foo: NonEmptyString = borsh::from_slice(&data).unwrap();
+        assert_eq!(foo, "foo");
+
+        let empty = borsh::to_vec(&String::new()).unwrap();
+        let err = borsh::from_slice::<NonEmptyString>(&empty).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
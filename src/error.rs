@@ -0,0 +1,88 @@
+use {
+    alloc::string::FromUtf8Error,
+    core::{
+        error::Error,
+        fmt::{Display, Formatter},
+        str::Utf8Error,
+    },
+};
+
+/// An error returned when trying to create a non-empty string from an empty source.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EmptyStringError;
+
+impl Display for EmptyStringError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        "string is empty".fmt(f)
+    }
+}
+
+impl Error for EmptyStringError {}
+
+/// An error returned when a fixed-capacity non-empty string (i.e. [`NonEmptyArrayString`](crate::NonEmptyArrayString))
+/// does not have enough capacity for the requested operation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CapacityError;
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        "insufficient string capacity".fmt(f)
+    }
+}
+
+impl Error for CapacityError {}
+
+/// An error returned when trying to create a [`NonEmptyStr`](crate::NonEmptyStr) from a byte slice.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NonEmptyUtf8Error {
+    /// The byte slice is empty.
+    Empty,
+    /// The byte slice is not valid UTF-8.
+    InvalidUtf8(Utf8Error),
+}
+
+impl Display for NonEmptyUtf8Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NonEmptyUtf8Error::Empty => EmptyStringError.fmt(f),
+            NonEmptyUtf8Error::InvalidUtf8(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for NonEmptyUtf8Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NonEmptyUtf8Error::Empty => None,
+            NonEmptyUtf8Error::InvalidUtf8(err) => Some(err),
+        }
+    }
+}
+
+/// An error returned when trying to create a [`NonEmptyString`](crate::NonEmptyString) from a byte vector.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NonEmptyFromUtf8Error {
+    /// The byte vector is empty.
+    Empty,
+    /// The byte vector is not valid UTF-8.
+    /// Contains the original bytes.
+    InvalidUtf8(FromUtf8Error),
+}
+
+impl Display for NonEmptyFromUtf8Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NonEmptyFromUtf8Error::Empty => EmptyStringError.fmt(f),
+            NonEmptyFromUtf8Error::InvalidUtf8(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for NonEmptyFromUtf8Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NonEmptyFromUtf8Error::Empty => None,
+            NonEmptyFromUtf8Error::InvalidUtf8(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use {
+    crate::*,
+    alloc::{borrow::Cow, string::String},
+};
+
+/// Extension methods for a [`Cow`] of a [`NonEmptyStr`].
+pub trait NonEmptyCowExt {
+    /// Returns the borrowed or owned string as a [`NonEmptyStr`].
+    fn as_ne_str(&self) -> &NonEmptyStr;
+
+    /// Extracts the owned [`NonEmptyString`], cloning the borrowed string if necessary.
+    ///
+    /// Same as [`Cow::into_owned`], but is explicitly typed.
+    fn into_owned_ne(self) -> NonEmptyString;
+}
+
+impl NonEmptyCowExt for Cow<'_, NonEmptyStr> {
+    fn as_ne_str(&self) -> &NonEmptyStr {
+        self.as_ref()
+    }
+
+    fn into_owned_ne(self) -> NonEmptyString {
+        self.into_owned()
+    }
+}
+
+/// Extension methods for a [`str`].
+pub trait StrExt {
+    /// Tries to borrow the string slice as a [`NonEmptyStr`].
+    /// Returns `None` if the string is empty.
+    ///
+    /// See [`NonEmptyStr::new`].
+    fn to_ne(&self) -> Option<&NonEmptyStr>;
+}
+
+impl StrExt for str {
+    fn to_ne(&self) -> Option<&NonEmptyStr> {
+        NonEmptyStr::new(self)
+    }
+}
+
+/// Extension methods for a [`String`].
+pub trait StringExt {
+    /// Tries to convert the string into a [`NonEmptyString`].
+    /// Returns `None` if the string is empty.
+    ///
+    /// See [`NonEmptyString::new`].
+    fn into_ne(self) -> Option<NonEmptyString>;
+}
+
+impl StringExt for String {
+    fn into_ne(self) -> Option<NonEmptyString> {
+        NonEmptyString::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_cow() {
+        let foo = ne_str!("foo");
+
+        let borrowed: Cow<'_, NonEmptyStr> = Cow::Borrowed(foo);
+        assert_eq!(borrowed.as_ne_str(), "foo");
+        assert_eq!(borrowed.as_ne_str().as_str(), "foo");
+        assert_eq!(borrowed.into_owned_ne(), "foo");
+
+        let owned: Cow<'_, NonEmptyStr> = Cow::Owned(foo.to_owned());
+        assert_eq!(owned.as_ne_str(), "foo");
+        assert_eq!(owned.into_owned_ne(), "foo");
+    }
+
+    #[test]
+    fn str_ext() {
+        assert_eq!("foo".to_ne().unwrap(), "foo");
+        assert!("".to_ne().is_none());
+
+        let foo = "foo".to_owned();
+        assert_eq!(foo.to_ne().unwrap(), "foo");
+
+        assert_eq!(
+            Some("foo")
+                .and_then(str::to_ne)
+                .map(NonEmptyStr::first_char),
+            Some('f')
+        );
+        assert!(Some("").and_then(str::to_ne).is_none());
+    }
+
+    #[test]
+    fn string_ext() {
+        assert_eq!("foo".to_owned().into_ne().unwrap(), "foo");
+        assert!(String::new().into_ne().is_none());
+    }
+}
@@ -1,39 +1,750 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+
+use {
+    crate::*,
+    core::hash::{BuildHasherDefault, Hash, Hasher},
 };
 
 /// Hashes the string literal `s` to a `u64` using the Rust's [`default hasher`](DefaultHasher) (i.e. one used in the [`HashMap`](std::collections::HashMap)).
+#[cfg(feature = "std")]
 pub fn str_hash_default(s: &str) -> u64 {
-    let mut hasher = DefaultHasher::new();
+    str_hash_with::<DefaultHasher>(s)
+}
+
+/// Hashes the string literal `s` to a `u64` using a [`default`](Default)-constructed hasher `H`.
+///
+/// The string is hashed via its [`Hash`] implementation, exactly like when it's used as a [`HashMap`](std::collections::HashMap) key.
+/// The result thus depends on how `H` implements [`Hasher::write`] / [`Hasher::write_str`]
+/// (e.g. by default [`str`] also writes a trailing `0xff` byte).
+pub fn str_hash_with<H: Hasher + Default>(s: &str) -> u64 {
+    let mut hasher = H::default();
     s.hash(&mut hasher);
     hasher.finish()
 }
 
+/// The FNV (32b) prime used by the FNV1a (32b) hash functions in this module.
+pub const FNV1A32_PRIME: u32 = 0x0100_0193;
+/// The FNV (32b) offset basis (i.e. the initial hash value) used by the FNV1a (32b) hash functions in this module.
+pub const FNV1A32_OFFSET: u32 = 0x811c_9dc5;
+
+/// The FNV (64b) prime used by the FNV1a (64b) hash functions in this module.
+pub const FNV1A64_PRIME: u64 = 0x0000_0100_0000_01B3;
+/// The FNV (64b) offset basis (i.e. the initial hash value) used by the FNV1a (64b) hash functions in this module.
+pub const FNV1A64_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The FNV (128b) prime used by the FNV1a (128b) hash functions in this module.
+pub const FNV1A128_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013B;
+/// The FNV (128b) offset basis (i.e. the initial hash value) used by the FNV1a (128b) hash functions in this module.
+pub const FNV1A128_OFFSET: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+
 /// Hashes the string literal `s` to a `u32` using the FNV1a (32b) hash.
-pub fn str_hash_fnv1a(s: &str) -> u32 {
-    const FNV1A32_PRIME: u32 = 0x0100_0193;
-    const FNV1A32_SEED: u32 = 0x811c_9dc5;
+pub const fn str_hash_fnv1a(s: &str) -> u32 {
+    fnv1a_bytes(s.as_bytes())
+}
+
+/// Hashes the string literal `s` to a `u32` using the FNV1a (32b) hash,
+/// starting from the provided `seed` instead of the standard FNV offset basis ([`FNV1A32_OFFSET`]).
+///
+/// NOTE: this is not a keyed hash in the cryptographic sense -
+/// the seed only decorrelates the hashes, it does not make them hard to predict.
+pub const fn str_hash_fnv1a_seeded(s: &str, seed: u32) -> u32 {
+    fnv1a_bytes_seeded(s.as_bytes(), seed)
+}
+
+/// Hashes the string literal `s` to a `u64` using the FNV1a (64b) hash.
+pub const fn str_hash_fnv1a_64(s: &str) -> u64 {
+    fnv1a_64_bytes(s.as_bytes())
+}
 
-    let mut hash = FNV1A32_SEED;
+/// Hashes the string literal `s` to a `u64` using the FNV1a (64b) hash,
+/// starting from the provided `seed` instead of the standard FNV offset basis ([`FNV1A64_OFFSET`]).
+///
+/// NOTE: this is not a keyed hash in the cryptographic sense -
+/// the seed only decorrelates the hashes, it does not make them hard to predict.
+pub const fn str_hash_fnv1a_64_seeded(s: &str, seed: u64) -> u64 {
+    fnv1a_64_bytes_seeded(s.as_bytes(), seed)
+}
+
+/// Hashes the `bytes` to a `u32` using the FNV1a (32b) hash.
+pub const fn fnv1a_bytes(bytes: &[u8]) -> u32 {
+    fnv1a_bytes_seeded(bytes, FNV1A32_OFFSET)
+}
 
-    for byte in s.as_bytes() {
-        hash = (hash ^ *byte as u32).wrapping_mul(FNV1A32_PRIME);
+/// Hashes the `bytes` to a `u32` using the FNV1a (32b) hash,
+/// starting from the provided `seed` instead of the standard FNV offset basis ([`FNV1A32_OFFSET`]).
+///
+/// See [`str_hash_fnv1a_seeded`].
+pub const fn fnv1a_bytes_seeded(bytes: &[u8], seed: u32) -> u32 {
+    let mut hash = seed;
+    let mut i = 0;
+
+    // `for` loops are not allowed in `const fn`'s.
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(FNV1A32_PRIME);
+        i += 1;
     }
 
     hash
 }
 
-/// Hashes the string literal `s` to a `u64` using the FNV1a (64b) hash.
-pub fn str_hash_fnv1a_64(s: &str) -> u64 {
-    const FNV1A64_PRIME: u64 = 0x0000_0100_0000_01B3;
-    const FNV1A64_SEED: u64 = 0xcbf2_9ce4_8422_2325;
+/// Hashes the `bytes` to a `u64` using the FNV1a (64b) hash.
+pub const fn fnv1a_64_bytes(bytes: &[u8]) -> u64 {
+    fnv1a_64_bytes_seeded(bytes, FNV1A64_OFFSET)
+}
+
+/// Hashes the `bytes` to a `u64` using the FNV1a (64b) hash,
+/// starting from the provided `seed` instead of the standard FNV offset basis ([`FNV1A64_OFFSET`]).
+///
+/// See [`str_hash_fnv1a_64_seeded`].
+pub const fn fnv1a_64_bytes_seeded(bytes: &[u8], seed: u64) -> u64 {
+    let mut hash = seed;
+    let mut i = 0;
+
+    // `for` loops are not allowed in `const fn`'s.
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(FNV1A64_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Hashes the string literal `s` to a `u128` using the FNV1a (128b) hash.
+pub const fn str_hash_fnv1a_128(s: &str) -> u128 {
+    fnv1a_128_bytes(s.as_bytes())
+}
+
+/// Hashes the string literal `s` using the FNV1a (128b) hash,
+/// returning the hash as big-endian bytes (i.e. the order in which the hash is usually printed).
+///
+/// See [`str_hash_fnv1a_128`].
+pub const fn str_hash_fnv1a_128_be_bytes(s: &str) -> [u8; 16] {
+    str_hash_fnv1a_128(s).to_be_bytes()
+}
+
+/// Hashes the `bytes` to a `u128` using the FNV1a (128b) hash.
+pub const fn fnv1a_128_bytes(bytes: &[u8]) -> u128 {
+    let mut hash = FNV1A128_OFFSET;
+    let mut i = 0;
+
+    // `for` loops are not allowed in `const fn`'s.
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u128).wrapping_mul(FNV1A128_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// A [`Hasher`] which uses the FNV1a (64b) hash.
+///
+/// A single [`write`](Hasher::write) of the string's bytes produces the same result as [`str_hash_fnv1a_64`].
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1a64Hasher(u64);
+
+impl Default for Fnv1a64Hasher {
+    fn default() -> Self {
+        Self(FNV1A64_OFFSET)
+    }
+}
+
+impl Hasher for Fnv1a64Hasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = fnv1a_64_bytes_seeded(bytes, self.0);
+    }
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) which creates [`Fnv1a64Hasher`]'s,
+/// e.g. for use with a [`HashMap`](std::collections::HashMap).
+pub type Fnv1aBuildHasher = BuildHasherDefault<Fnv1a64Hasher>;
+
+/// Incrementally hashes a sequence of string chunks using the FNV1a (64b) hash.
+///
+/// The result is the same as hashing the concatenation of all chunks with [`str_hash_fnv1a_64`],
+/// but does not require allocating the concatenated string.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1a64(u64);
+
+impl Fnv1a64 {
+    /// Creates a new [`Fnv1a64`] hash state, as if no chunks were hashed yet.
+    pub const fn new() -> Self {
+        Self(FNV1A64_OFFSET)
+    }
+
+    /// Hashes the next non-empty string chunk `s`.
+    pub fn update(&mut self, s: &NonEmptyStr) {
+        self.update_str(s.as_str())
+    }
+
+    /// Hashes the next string chunk `s`.
+    pub fn update_str(&mut self, s: &str) {
+        self.0 = fnv1a_64_bytes_seeded(s.as_bytes(), self.0);
+    }
+
+    /// Returns the hash of all chunks hashed so far.
+    pub const fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for Fnv1a64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes the string literal `s` to a `u32` using the CRC32 checksum
+/// (reflected IEEE polynomial `0xEDB88320`, same as zlib's `crc32`).
+pub fn str_hash_crc32(s: &str) -> u32 {
+    crc32_bytes(s.as_bytes())
+}
+
+/// Calculates the CRC32 checksum of the `bytes`
+/// (reflected IEEE polynomial `0xEDB88320`, same as zlib's `crc32`).
+pub fn crc32_bytes(bytes: &[u8]) -> u32 {
+    const CRC32_TABLE: [u32; 256] = crc32_table();
+
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc = CRC32_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+
+    !crc
+}
+
+const fn crc32_table() -> [u32; 256] {
+    const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                CRC32_POLYNOMIAL ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
+
+/// Hashes the string literal `s` to a `u32` using the MurmurHash3 (x86, 32b) hash with the provided `seed`.
+pub fn str_hash_murmur3_32(s: &str, seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    fn scramble(k: u32) -> u32 {
+        k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+    }
+
+    let bytes = s.as_bytes();
+    let mut hash = seed;
+
+    let mut blocks = bytes.chunks_exact(4);
 
-    let mut hash = FNV1A64_SEED;
+    for block in &mut blocks {
+        let k = u32::from_le_bytes(block.try_into().unwrap());
 
-    for byte in s.as_bytes() {
-        hash = (hash ^ *byte as u64).wrapping_mul(FNV1A64_PRIME);
+        hash ^= scramble(k);
+        hash = hash
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe654_6b64);
     }
 
+    let tail = blocks.remainder();
+
+    if !tail.is_empty() {
+        let mut k = 0;
+
+        for (i, byte) in tail.iter().enumerate() {
+            k |= (*byte as u32) << (8 * i);
+        }
+
+        hash ^= scramble(k);
+    }
+
+    hash ^= bytes.len() as u32;
+
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+
     hash
 }
+
+/// Hashes the string literal `s` to a `u64` using the xxHash (XXH64) hash with the seed `0`.
+pub fn str_hash_xxh64(s: &str) -> u64 {
+    str_hash_xxh64_seeded(s, 0)
+}
+
+/// Hashes the string literal `s` to a `u64` using the xxHash (XXH64) hash with the provided `seed`.
+pub fn str_hash_xxh64_seeded(s: &str, seed: u64) -> u64 {
+    const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+    const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+    const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+    const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+    const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+    fn round(acc: u64, input: u64) -> u64 {
+        acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+            .rotate_left(31)
+            .wrapping_mul(PRIME64_1)
+    }
+
+    fn merge_round(acc: u64, val: u64) -> u64 {
+        (acc ^ round(0, val))
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4)
+    }
+
+    fn read_u64(bytes: &[u8]) -> u64 {
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+
+    fn read_u32(bytes: &[u8]) -> u32 {
+        u32::from_le_bytes(bytes[..4].try_into().unwrap())
+    }
+
+    let bytes = s.as_bytes();
+    let mut rest = bytes;
+
+    let mut hash = if bytes.len() >= 32 {
+        let mut v1 = seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2);
+        let mut v2 = seed.wrapping_add(PRIME64_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(PRIME64_1);
+
+        let mut stripes = bytes.chunks_exact(32);
+
+        for stripe in &mut stripes {
+            v1 = round(v1, read_u64(&stripe[0..]));
+            v2 = round(v2, read_u64(&stripe[8..]));
+            v3 = round(v3, read_u64(&stripe[16..]));
+            v4 = round(v4, read_u64(&stripe[24..]));
+        }
+
+        rest = stripes.remainder();
+
+        let mut hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+
+        hash = merge_round(hash, v1);
+        hash = merge_round(hash, v2);
+        hash = merge_round(hash, v3);
+        merge_round(hash, v4)
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while rest.len() >= 8 {
+        hash ^= round(0, read_u64(rest));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        rest = &rest[8..];
+    }
+
+    if rest.len() >= 4 {
+        hash ^= (read_u32(rest) as u64).wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        rest = &rest[4..];
+    }
+
+    for byte in rest {
+        hash ^= (*byte as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^= hash >> 32;
+
+    hash
+}
+
+/// Hashes the string literal `s` to a `u64` using the SipHash-2-4 hash keyed with the 128-bit `key` (`(k0, k1)`).
+///
+/// Unlike the other (unkeyed or merely seeded) hashes in this module, SipHash with a secret random `key`
+/// is resistant to hash-flooding attacks, and thus suitable for hashing untrusted input.
+pub fn str_hash_siphash(s: &str, key: (u64, u64)) -> u64 {
+    fn sip_round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13);
+        v[1] ^= v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16);
+        v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21);
+        v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17);
+        v[1] ^= v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    let (k0, k1) = key;
+
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+
+    let bytes = s.as_bytes();
+
+    let mut blocks = bytes.chunks_exact(8);
+
+    for block in &mut blocks {
+        let m = u64::from_le_bytes(block.try_into().unwrap());
+
+        v[3] ^= m;
+        sip_round(&mut v);
+        sip_round(&mut v);
+        v[0] ^= m;
+    }
+
+    // The last block contains the remaining bytes and the input length in the most significant byte.
+    let mut m = (bytes.len() as u64) << 56;
+
+    for (i, byte) in blocks.remainder().iter().enumerate() {
+        m |= (*byte as u64) << (8 * i);
+    }
+
+    v[3] ^= m;
+    sip_round(&mut v);
+    sip_round(&mut v);
+    v[0] ^= m;
+
+    v[2] ^= 0xff;
+    sip_round(&mut v);
+    sip_round(&mut v);
+    sip_round(&mut v);
+    sip_round(&mut v);
+
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+/// Hashes the string literal `s` to a `u32` using the djb2 hash.
+///
+/// Implements the original, additive variant (`hash = hash * 33 + c`, starting from `5381`),
+/// not the XOR variant (`hash = hash * 33 ^ c`, sometimes called djb2a).
+pub const fn str_hash_djb2(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    let mut hash: u32 = 5381;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash = hash.wrapping_mul(33).wrapping_add(bytes[i] as u32);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Hashes the string literal `s` to a `u32` using the sdbm hash
+/// (`hash = c + (hash << 6) + (hash << 16) - hash`, starting from `0`).
+pub const fn str_hash_sdbm(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    let mut hash: u32 = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash = (bytes[i] as u32)
+            .wrapping_add(hash << 6)
+            .wrapping_add(hash << 16)
+            .wrapping_sub(hash);
+        i += 1;
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::HashMap};
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(str_hash_fnv1a(""), 0x811c_9dc5);
+        assert_eq!(str_hash_fnv1a("a"), 0xe40c_292c);
+        assert_eq!(str_hash_fnv1a("foobar"), 0xbf9c_f968);
+
+        assert_eq!(str_hash_fnv1a_64(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(str_hash_fnv1a_64("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(str_hash_fnv1a_64("foobar"), 0x8594_4171_f739_67e8);
+
+        assert_eq!(str_hash_fnv1a(""), FNV1A32_OFFSET);
+        assert_eq!(str_hash_fnv1a_64(""), FNV1A64_OFFSET);
+        assert_eq!(str_hash_fnv1a_128(""), FNV1A128_OFFSET);
+
+        assert_eq!(
+            str_hash_fnv1a_seeded("foobar", 0x811c_9dc5),
+            str_hash_fnv1a("foobar")
+        );
+        assert_ne!(str_hash_fnv1a_seeded("foobar", 7), str_hash_fnv1a("foobar"));
+        assert_eq!(str_hash_fnv1a_seeded("", 7), 7);
+
+        assert_eq!(
+            str_hash_fnv1a_64_seeded("foobar", 0xcbf2_9ce4_8422_2325),
+            str_hash_fnv1a_64("foobar")
+        );
+        assert_ne!(
+            str_hash_fnv1a_64_seeded("foobar", 7),
+            str_hash_fnv1a_64("foobar")
+        );
+        assert_eq!(str_hash_fnv1a_64_seeded("", 7), 7);
+    }
+
+    #[test]
+    fn fnv1a_128() {
+        assert_eq!(
+            str_hash_fnv1a_128(""),
+            0x6c62_272e_07bb_0142_62b8_2175_6295_c58d
+        );
+        assert_eq!(
+            str_hash_fnv1a_128("a"),
+            0xd228_cb69_6f1a_8caf_7891_2b70_4e4a_8964
+        );
+        assert_eq!(
+            str_hash_fnv1a_128("foobar"),
+            0x343e_1662_793c_64bf_6f0d_3597_ba44_6f18
+        );
+
+        assert_eq!(
+            str_hash_fnv1a_128_be_bytes("a"),
+            [
+                0xd2, 0x28, 0xcb, 0x69, 0x6f, 0x1a, 0x8c, 0xaf, 0x78, 0x91, 0x2b, 0x70, 0x4e, 0x4a,
+                0x89, 0x64
+            ]
+        );
+
+        assert_eq!(fnv1a_128_bytes(b"foobar"), str_hash_fnv1a_128("foobar"));
+
+        const FOO_HASH: u128 = str_hash_fnv1a_128("foobar");
+        assert_eq!(FOO_HASH, str_hash_fnv1a_128("foobar"));
+    }
+
+    #[test]
+    fn fnv1a_bytes_match_str() {
+        for s in ["", "a", "foobar", "💡"] {
+            assert_eq!(fnv1a_bytes(s.as_bytes()), str_hash_fnv1a(s));
+            assert_eq!(fnv1a_64_bytes(s.as_bytes()), str_hash_fnv1a_64(s));
+            assert_eq!(
+                fnv1a_bytes_seeded(s.as_bytes(), 7),
+                str_hash_fnv1a_seeded(s, 7)
+            );
+            assert_eq!(
+                fnv1a_64_bytes_seeded(s.as_bytes(), 7),
+                str_hash_fnv1a_64_seeded(s, 7)
+            );
+        }
+
+        // Not valid UTF-8.
+        assert_eq!(fnv1a_bytes(&[0xff]), 0x7a0b_824e);
+    }
+
+    #[test]
+    fn fnv1a_const() {
+        const FOO: u32 = str_hash_fnv1a("foo");
+        const FOO_64: u64 = str_hash_fnv1a_64("foo");
+
+        assert_eq!(FOO, str_hash_fnv1a("foo"));
+        assert_eq!(FOO_64, str_hash_fnv1a_64("foo"));
+
+        match str_hash_fnv1a("foo") {
+            FOO => {}
+            _ => panic!("hash mismatch"),
+        }
+    }
+
+    #[test]
+    fn fnv1a_hasher() {
+        let mut hasher = Fnv1a64Hasher::default();
+        hasher.write("foobar".as_bytes());
+        assert_eq!(hasher.finish(), str_hash_fnv1a_64("foobar"));
+
+        let mut hasher = Fnv1a64Hasher::default();
+        hasher.write("foo".as_bytes());
+        hasher.write("bar".as_bytes());
+        assert_eq!(hasher.finish(), str_hash_fnv1a_64("foobar"));
+
+        let mut map = HashMap::<NonEmptyString, u32, Fnv1aBuildHasher>::default();
+        map.insert("foo".parse().unwrap(), 7);
+        assert_eq!(map.get(NonEmptyStr::new("foo").unwrap()), Some(&7));
+    }
+
+    #[test]
+    fn fnv1a_streaming() {
+        assert_eq!(Fnv1a64::new().finish(), str_hash_fnv1a_64(""));
+
+        let mut hash = Fnv1a64::new();
+        hash.update(ne_str!("foo"));
+        assert_eq!(hash.finish(), str_hash_fnv1a_64("foo"));
+
+        let mut hash = Fnv1a64::default();
+        for chunk in ["usr", "/", "local", "/", "bin"] {
+            hash.update(NonEmptyStr::new(chunk).unwrap());
+        }
+        hash.update_str("");
+        assert_eq!(hash.finish(), str_hash_fnv1a_64("usr/local/bin"));
+        assert_eq!(hash.finish(), ne_str!("usr/local/bin").hash_fnv1a_64());
+    }
+
+    #[test]
+    fn crc32() {
+        // Reference values from zlib's `crc32`.
+        assert_eq!(str_hash_crc32(""), 0);
+        assert_eq!(str_hash_crc32("a"), 0xE8B7_BE43);
+        assert_eq!(str_hash_crc32("123456789"), 0xCBF4_3926);
+        assert_eq!(
+            str_hash_crc32("The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+
+        assert_eq!(crc32_bytes(b"123456789"), str_hash_crc32("123456789"));
+    }
+
+    #[test]
+    fn murmur3_32() {
+        // Reference values from the canonical `MurmurHash3_x86_32`.
+        assert_eq!(str_hash_murmur3_32("", 0), 0);
+        assert_eq!(str_hash_murmur3_32("", 1), 0x514E_28B7);
+        assert_eq!(str_hash_murmur3_32("", 0xffff_ffff), 0x81F1_6F39);
+        assert_eq!(str_hash_murmur3_32("a", 0x9747_b28c), 0x7FA0_9EA6);
+        assert_eq!(str_hash_murmur3_32("abc", 0x9747_b28c), 0xC84A_62DD);
+        assert_eq!(str_hash_murmur3_32("aaaa", 0x9747_b28c), 0x5A97_808A);
+        assert_eq!(str_hash_murmur3_32("abcd", 0x9747_b28c), 0xF047_8627);
+        assert_eq!(
+            str_hash_murmur3_32("Hello, world!", 0x9747_b28c),
+            0x2488_4CBA
+        );
+        assert_eq!(
+            str_hash_murmur3_32("The quick brown fox jumps over the lazy dog", 0x9747_b28c),
+            0x2FA8_26CD
+        );
+        assert_eq!(
+            str_hash_murmur3_32("The quick brown fox jumps over the lazy dog", 0),
+            0x2E4F_F723
+        );
+    }
+
+    #[test]
+    fn xxh64() {
+        // Reference values from the xxHash spec / reference implementation.
+        assert_eq!(str_hash_xxh64(""), 0xEF46_DB37_51D8_E999);
+        assert_eq!(str_hash_xxh64("a"), 0xD24E_C4F1_A98C_6E5B);
+        assert_eq!(str_hash_xxh64("abc"), 0x44BC_2CF5_AD77_0999);
+        assert_eq!(
+            str_hash_xxh64("abcdefghijklmnopqrstuvwxyz"),
+            0xCFE1_F278_FA89_835C
+        );
+        assert_eq!(
+            str_hash_xxh64(
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            ),
+            0xE04A_477F_19EE_145D
+        );
+
+        assert_eq!(str_hash_xxh64_seeded("abc", 0), str_hash_xxh64("abc"));
+        assert_ne!(str_hash_xxh64_seeded("abc", 1), str_hash_xxh64("abc"));
+    }
+
+    #[test]
+    fn siphash() {
+        // Test vectors from the SipHash reference implementation (`vectors.h`, SipHash-2-4, 64b output):
+        // the key is the bytes `00..0f`, the message of length `n` is the bytes `00..n`.
+        let key = (0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908);
+        let message: Vec<u8> = (0..64).collect();
+        let message = core::str::from_utf8(&message).unwrap();
+
+        assert_eq!(str_hash_siphash(&message[..0], key), 0x726F_DB47_DD0E_0E31);
+        assert_eq!(str_hash_siphash(&message[..1], key), 0x74F8_39C5_93DC_67FD);
+        assert_eq!(str_hash_siphash(&message[..7], key), 0xAB02_00F5_8B01_D137);
+        assert_eq!(str_hash_siphash(&message[..8], key), 0x93F5_F579_9A93_2462);
+        assert_eq!(str_hash_siphash(&message[..15], key), 0xA129_CA61_49BE_45E5);
+        assert_eq!(str_hash_siphash(&message[..16], key), 0x3F2A_CC7F_57C2_9BDB);
+        assert_eq!(str_hash_siphash(&message[..63], key), 0x958A_324C_EB06_4572);
+
+        assert_eq!(str_hash_siphash("foo", (0, 0)), 0xDDE3_8BD4_8EAE_7414);
+        assert_ne!(
+            str_hash_siphash("foo", (0, 0)),
+            str_hash_siphash("foo", (0, 1))
+        );
+    }
+
+    #[test]
+    fn djb2() {
+        assert_eq!(str_hash_djb2(""), 5381);
+        assert_eq!(str_hash_djb2("a"), 0x0002_B606);
+        assert_eq!(str_hash_djb2("foo"), 0x0B88_7389);
+        assert_eq!(str_hash_djb2("hello"), 0x0F92_3099);
+        assert_eq!(
+            str_hash_djb2("The quick brown fox jumps over the lazy dog"),
+            0x34CC_38DE
+        );
+
+        const FOO_HASH: u32 = str_hash_djb2("foo");
+        assert_eq!(FOO_HASH, 0x0B88_7389);
+    }
+
+    #[test]
+    fn sdbm() {
+        assert_eq!(str_hash_sdbm(""), 0);
+        assert_eq!(str_hash_sdbm("a"), 0x61);
+        assert_eq!(str_hash_sdbm("foo"), 0x32A9_4926);
+        assert_eq!(str_hash_sdbm("hello"), 0x28D1_9932);
+        assert_eq!(
+            str_hash_sdbm("The quick brown fox jumps over the lazy dog"),
+            0x8CA7_7173
+        );
+
+        const FOO_HASH: u32 = str_hash_sdbm("foo");
+        assert_eq!(FOO_HASH, 0x32A9_4926);
+    }
+
+    #[test]
+    fn hash_with() {
+        #[cfg(feature = "std")]
+        assert_eq!(
+            str_hash_with::<DefaultHasher>("foo"),
+            str_hash_default("foo")
+        );
+
+        let mut hasher = Fnv1a64Hasher::default();
+        "foo".hash(&mut hasher);
+        assert_eq!(str_hash_with::<Fnv1a64Hasher>("foo"), hasher.finish());
+    }
+}
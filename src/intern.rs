@@ -0,0 +1,136 @@
+use {
+    crate::*,
+    alloc::{collections::BTreeMap, vec::Vec},
+};
+
+/// Stores unique [`NonEmptyString`]'s and identifies them with `u32` ids.
+///
+/// Interning the same string again returns the same id.
+/// Ids are assigned sequentially starting from `0` and stay valid for the lifetime of the interner.
+#[derive(Clone, Default, Debug)]
+pub struct NonEmptyStringInterner {
+    strings: Vec<NonEmptyString>,
+    // FNV1a (64b) hash -> ids of the interned strings with this hash.
+    // Hash collisions are resolved by comparing the strings.
+    lookup: BTreeMap<u64, Vec<u32>>,
+}
+
+impl NonEmptyStringInterner {
+    /// Creates a new empty [`NonEmptyStringInterner`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns the string `s` (if it was not interned yet) and returns its id.
+    ///
+    /// # Panics
+    /// Panics if the number of interned strings would overflow a `u32`.
+    pub fn intern(&mut self, s: &NonEmptyStr) -> u32 {
+        self.intern_with_hash(s.hash_fnv1a_64(), s)
+    }
+
+    /// Returns the id of the string `s` if it was interned, or `None` otherwise.
+    pub fn get(&self, s: &NonEmptyStr) -> Option<u32> {
+        self.get_with_hash(s.hash_fnv1a_64(), s)
+    }
+
+    /// Split from [`intern`](NonEmptyStringInterner::intern) so tests may force hash collisions.
+    fn intern_with_hash(&mut self, hash: u64, s: &NonEmptyStr) -> u32 {
+        let ids = self.lookup.entry(hash).or_default();
+
+        if let Some(id) = ids
+            .iter()
+            .copied()
+            .find(|&id| self.strings[id as usize] == s)
+        {
+            return id;
+        }
+
+        let id = u32::try_from(self.strings.len()).expect("interned string id overflow");
+
+        self.strings.push(NonEmptyString::from(s));
+        ids.push(id);
+
+        id
+    }
+
+    fn get_with_hash(&self, hash: u64, s: &NonEmptyStr) -> Option<u32> {
+        self.lookup
+            .get(&hash)?
+            .iter()
+            .copied()
+            .find(|&id| self.strings[id as usize] == s)
+    }
+
+    /// Returns the interned string with the `id`, or `None` if the `id` is invalid.
+    pub fn resolve(&self, id: u32) -> Option<&NonEmptyStr> {
+        self.strings.get(id as usize).map(NonEmptyString::as_ne_str)
+    }
+
+    /// Returns the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings were interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let mut interner = NonEmptyStringInterner::new();
+        assert!(interner.is_empty());
+
+        let foo = interner.intern(ne_str!("foo"));
+        let bar = interner.intern(ne_str!("bar"));
+
+        assert_eq!(foo, 0);
+        assert_eq!(bar, 1);
+        assert_ne!(foo, bar);
+        assert_eq!(interner.len(), 2);
+
+        assert_eq!(interner.intern(ne_str!("foo")), foo);
+        assert_eq!(interner.intern(&NonEmptyString::from(ne_str!("bar"))), bar);
+        assert_eq!(interner.len(), 2);
+
+        assert_eq!(interner.get(ne_str!("foo")), Some(foo));
+        assert_eq!(interner.get(ne_str!("baz")), None);
+
+        assert_eq!(interner.resolve(foo).unwrap(), "foo");
+        assert_eq!(interner.resolve(bar).unwrap(), "bar");
+        assert!(interner.resolve(2).is_none());
+    }
+
+    #[test]
+    fn hash_collision() {
+        let mut interner = NonEmptyStringInterner::new();
+
+        // Pretend the strings hash to the same value.
+        let hash = 7;
+
+        let foo = interner.intern_with_hash(hash, ne_str!("foo"));
+        let bar = interner.intern_with_hash(hash, ne_str!("bar"));
+
+        assert_ne!(foo, bar);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.lookup.len(), 1);
+        assert_eq!(interner.lookup[&hash], [foo, bar]);
+
+        assert_eq!(interner.intern_with_hash(hash, ne_str!("foo")), foo);
+        assert_eq!(interner.intern_with_hash(hash, ne_str!("bar")), bar);
+        assert_eq!(interner.len(), 2);
+
+        assert_eq!(interner.get_with_hash(hash, ne_str!("foo")), Some(foo));
+        assert_eq!(interner.get_with_hash(hash, ne_str!("bar")), Some(bar));
+        assert_eq!(interner.get_with_hash(hash, ne_str!("baz")), None);
+
+        assert_eq!(interner.resolve(foo).unwrap(), "foo");
+        assert_eq!(interner.resolve(bar).unwrap(), "bar");
+    }
+}
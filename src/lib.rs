@@ -1,9 +1,87 @@
 //! Exports some string utility types and functions.
+//!
+//! Supports `no_std` (with `alloc`) when the default `std` feature is disabled.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+mod error;
+mod ext;
 mod hash;
+mod intern;
+mod non_empty_array_string;
+mod non_empty_byte_slice;
+#[cfg(feature = "compact_str")]
+mod non_empty_compact_string;
 mod non_empty_str;
 mod non_empty_string;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
+pub use error::*;
+pub use ext::*;
 pub use hash::*;
+pub use intern::*;
+pub use non_empty_array_string::*;
+pub use non_empty_byte_slice::*;
+#[cfg(feature = "compact_str")]
+pub use non_empty_compact_string::*;
 pub use non_empty_str::*;
 pub use non_empty_string::*;
+#[cfg(feature = "proptest")]
+pub use proptest_impl::*;
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::*;
+
+#[cfg(test)]
+mod thread_safety {
+    use {
+        super::*,
+        alloc::{boxed::Box, sync::Arc},
+        std::thread,
+    };
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // Fails to compile if any of the types stop being `Send + Sync`.
+    const _: fn() = || {
+        assert_send_sync::<NonEmptyString>();
+        assert_send_sync::<&NonEmptyStr>();
+        assert_send_sync::<&NonEmptyByteSlice>();
+        assert_send_sync::<Box<NonEmptyStr>>();
+        assert_send_sync::<Arc<NonEmptyStr>>();
+    };
+
+    #[test]
+    fn send_sync() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+        let boxed = foo.clone().into_boxed_ne_str();
+        let shared: Arc<NonEmptyStr> = foo.as_ne_str().into();
+        let shared_clone = shared.clone();
+
+        let foo = thread::spawn(move || {
+            assert_eq!(*boxed, "foo");
+            assert_eq!(*shared_clone, "foo");
+            foo
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(foo, "foo");
+        assert_eq!(*shared, "foo");
+
+        let foo_str: &'static NonEmptyStr = ne_str!("foo");
+        assert_eq!(thread::spawn(move || foo_str).join().unwrap(), "foo");
+    }
+}
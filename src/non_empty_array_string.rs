@@ -0,0 +1,334 @@
+use {
+    crate::*,
+    core::{
+        borrow::Borrow,
+        cmp::{Ordering, PartialEq},
+        fmt::{Debug, Display, Formatter},
+        hash::{Hash, Hasher},
+        num::NonZeroUsize,
+        ops::Deref,
+    },
+    miniunchecked::*,
+};
+
+/// A non-empty UTF-8 string stored inline in a fixed-capacity buffer of `N` bytes.
+///
+/// The allocation-free counterpart of the [`NonEmptyString`].
+///
+/// Hashes and compares exactly like the wrapped [`str`],
+/// so it may be looked up in hash maps and sets by a `&str` key.
+#[derive(Clone, Copy)]
+pub struct NonEmptyArrayString<const N: usize> {
+    buf: [u8; N],
+    len: NonZeroUsize,
+}
+
+impl<const N: usize> NonEmptyArrayString<N> {
+    /// Tries to create a [`NonEmptyArrayString`] from the string slice `s`.
+    /// Returns `None` if the string `s` is empty or does not fit in `N` bytes.
+    pub fn new(s: &str) -> Option<Self> {
+        if s.len() > N {
+            return None;
+        }
+
+        let len = NonZeroUsize::new(s.len())?;
+
+        let mut buf = [0; N];
+        buf[..len.get()].copy_from_slice(s.as_bytes());
+
+        Some(Self { buf, len })
+    }
+
+    /// Returns the capacity of this [`NonEmptyArrayString`] in bytes (i.e. `N`).
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(self.buf.get_unchecked(..self.len.get())) }
+    }
+
+    pub fn as_ne_str(&self) -> &NonEmptyStr {
+        unsafe { NonEmptyStr::new_unchecked(self.as_str()) }
+    }
+
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        self.len
+    }
+
+    /// Returns the length of this [`NonEmptyArrayString`] in bytes.
+    /// Never returns `0`; see also [`len_nonzero`](NonEmptyArrayString::len_nonzero).
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Appends the string slice `s` to the end of this [`NonEmptyArrayString`].
+    ///
+    /// Returns an error and leaves the string unchanged if there is not enough capacity for `s`.
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let len = self.len.get();
+        let new_len = len + s.len();
+
+        if new_len > N {
+            return Err(CapacityError);
+        }
+
+        self.buf[len..new_len].copy_from_slice(s.as_bytes());
+        self.len = unsafe {
+            NonZeroUsize::new(new_len)
+                .unwrap_unchecked_dbg_msg("non-empty strings have non-zero length")
+        };
+
+        Ok(())
+    }
+
+    /// Appends the char `c` to the end of this [`NonEmptyArrayString`].
+    ///
+    /// Returns an error and leaves the string unchanged if there is not enough capacity for `c`.
+    pub fn push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<const N: usize> Deref for NonEmptyArrayString<N> {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ne_str()
+    }
+}
+
+impl<const N: usize> AsRef<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_ne_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_ne_str()
+    }
+}
+
+impl<const N: usize> Borrow<str> for NonEmptyArrayString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'s, const N: usize> From<&'s NonEmptyArrayString<N>> for &'s NonEmptyStr {
+    fn from(s: &'s NonEmptyArrayString<N>) -> Self {
+        s.as_ne_str()
+    }
+}
+
+impl<const N: usize> From<&NonEmptyArrayString<N>> for NonEmptyString {
+    fn from(s: &NonEmptyArrayString<N>) -> Self {
+        NonEmptyString::from(s.as_ne_str())
+    }
+}
+
+impl<const N: usize> Hash for NonEmptyArrayString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> Ord for NonEmptyArrayString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for NonEmptyArrayString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Comparsions.
+
+// <NonEmptyArrayString>
+////////////////////////////////////////////////////////////
+impl<const N: usize> PartialEq for NonEmptyArrayString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+impl<const N: usize> Eq for NonEmptyArrayString<N> {}
+////////////////////////////////////////////////////////////
+
+// <str>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl<const N: usize> PartialEq<str> for NonEmptyArrayString<N> {
+    fn eq(&self, other: &str) -> bool {
+        PartialEq::eq(self.as_str(), other)
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for NonEmptyArrayString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        PartialEq::eq(self.as_str(), *other)
+    }
+}
+
+// Reverse
+
+impl<const N: usize> PartialEq<NonEmptyArrayString<N>> for str {
+    fn eq(&self, other: &NonEmptyArrayString<N>) -> bool {
+        PartialEq::eq(self, other.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq<NonEmptyArrayString<N>> for &str {
+    fn eq(&self, other: &NonEmptyArrayString<N>) -> bool {
+        PartialEq::eq(*self, other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <NonEmptyStr>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl<const N: usize> PartialEq<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ne_str(), other)
+    }
+}
+
+impl<const N: usize> PartialEq<&NonEmptyStr> for NonEmptyArrayString<N> {
+    fn eq(&self, other: &&NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ne_str(), *other)
+    }
+}
+
+// Reverse
+
+impl<const N: usize> PartialEq<NonEmptyArrayString<N>> for NonEmptyStr {
+    fn eq(&self, other: &NonEmptyArrayString<N>) -> bool {
+        PartialEq::eq(self, other.as_ne_str())
+    }
+}
+
+impl<const N: usize> PartialEq<NonEmptyArrayString<N>> for &NonEmptyStr {
+    fn eq(&self, other: &NonEmptyArrayString<N>) -> bool {
+        PartialEq::eq(*self, other.as_ne_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+impl<const N: usize> Debug for NonEmptyArrayString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NonEmptyArrayString")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> Display for NonEmptyArrayString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::HashSet};
+
+    fn cmp<const N: usize>(nes: &NonEmptyArrayString<N>, s: &str) {
+        assert_eq!(nes, s);
+        assert_eq!(s, nes);
+        assert_eq!(nes.as_str(), s);
+        assert_eq!(nes.as_ne_str(), s);
+        assert_eq!(nes.deref(), s);
+        assert_eq!(nes.len(), s.len());
+    }
+
+    #[test]
+    fn non_empty_array_string() {
+        let foo = NonEmptyArrayString::<4>::new("foo").unwrap();
+        cmp(&foo, "foo");
+        assert_eq!(foo.capacity(), 4);
+        assert_eq!(AsRef::<[u8]>::as_ref(&foo), b"foo");
+
+        assert_eq!(foo, NonEmptyArrayString::<4>::new("foo").unwrap());
+        assert_ne!(foo, NonEmptyArrayString::<4>::new("bar").unwrap());
+        assert_eq!(foo, ne_str!("foo"));
+        assert_eq!(ne_str!("foo"), foo);
+        assert!(foo < NonEmptyArrayString::<4>::new("zzz").unwrap());
+
+        let ne_foo_str: NonEmptyString = (&foo).into();
+        assert_eq!(ne_foo_str, "foo");
+
+        // Exactly fits.
+        cmp(&NonEmptyArrayString::<3>::new("foo").unwrap(), "foo");
+
+        // Empty.
+        assert!(NonEmptyArrayString::<4>::new("").is_none());
+        assert!(NonEmptyArrayString::<0>::new("").is_none());
+
+        // Too long.
+        assert!(NonEmptyArrayString::<2>::new("foo").is_none());
+    }
+
+    #[test]
+    fn push() {
+        let mut foo = NonEmptyArrayString::<7>::new("f").unwrap();
+
+        foo.push_str("oo").unwrap();
+        cmp(&foo, "foo");
+
+        foo.push('ö').unwrap();
+        cmp(&foo, "fooö");
+
+        foo.push_str("").unwrap();
+        cmp(&foo, "fooö");
+
+        // Does not fit - the string is unchanged.
+        assert_eq!(foo.push_str("bar"), Err(CapacityError));
+        cmp(&foo, "fooö");
+
+        assert_eq!(foo.push('💡'), Err(CapacityError));
+        cmp(&foo, "fooö");
+
+        foo.push_str("ba").unwrap();
+        cmp(&foo, "fooöba");
+        assert_eq!(foo.len_nonzero().get(), foo.capacity());
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = HashSet::new();
+        set.insert(NonEmptyArrayString::<8>::new("foo").unwrap());
+
+        assert!(set.contains("foo"));
+        assert!(set.contains(ne_str!("foo")));
+        assert!(!set.contains("bar"));
+    }
+
+    #[test]
+    fn display() {
+        let foo = NonEmptyArrayString::<8>::new("foo").unwrap();
+        assert_eq!(format!("{}", foo), "foo");
+        assert_eq!(format!("{:?}", foo), r#"NonEmptyArrayString("foo")"#);
+    }
+}
@@ -0,0 +1,167 @@
+use {
+    core::{
+        borrow::Borrow,
+        fmt::{Debug, Formatter},
+        num::NonZeroUsize,
+        ops::Deref,
+    },
+    miniunchecked::*,
+};
+
+/// A non-empty byte slice.
+///
+/// The byte-level counterpart of the [`NonEmptyStr`](crate::NonEmptyStr)
+/// (see [`NonEmptyStr::as_ne_bytes`](crate::NonEmptyStr::as_ne_bytes)).
+///
+/// Hashes and compares exactly like the wrapped byte slice.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyByteSlice([u8]);
+
+impl NonEmptyByteSlice {
+    /// Tries to create a [`NonEmptyByteSlice`] from the byte slice `bytes`.
+    /// Returns `None` if `bytes` are empty.
+    pub fn new(bytes: &[u8]) -> Option<&Self> {
+        if bytes.is_empty() {
+            None
+        } else {
+            Some(unsafe { Self::new_unchecked(bytes) })
+        }
+    }
+
+    /// Creates a [`NonEmptyByteSlice`] from the byte slice `bytes`
+    /// without checking if it is empty.
+    ///
+    /// # Safety
+    /// The caller guarantees the byte slice `bytes` is not empty.
+    /// Passing an empty byte slice is undefined behaviour.
+    ///
+    /// # Panics
+    /// In debug configuration only, panics if `bytes` are empty.
+    pub const unsafe fn new_unchecked(bytes: &[u8]) -> &Self {
+        if cfg!(debug_assertions) && bytes.is_empty() {
+            panic!("tried to create a non-empty byte slice from an empty source");
+        }
+        &*(bytes as *const [u8] as *const _)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        unsafe {
+            NonZeroUsize::new(self.0.len())
+                .unwrap_unchecked_dbg_msg("non-empty byte slices have non-zero length")
+        }
+    }
+
+    /// Returns the length of this [`NonEmptyByteSlice`].
+    /// Never returns `0`; see also [`len_nonzero`](NonEmptyByteSlice::len_nonzero).
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the first byte of this [`NonEmptyByteSlice`].
+    pub fn first(&self) -> u8 {
+        unsafe {
+            *self
+                .0
+                .first()
+                .unwrap_unchecked_dbg_msg("non-empty byte slices have at least one byte")
+        }
+    }
+
+    /// Returns the last byte of this [`NonEmptyByteSlice`].
+    pub fn last(&self) -> u8 {
+        unsafe {
+            *self
+                .0
+                .last()
+                .unwrap_unchecked_dbg_msg("non-empty byte slices have at least one byte")
+        }
+    }
+}
+
+impl Deref for NonEmptyByteSlice {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyByteSlice {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<[u8]> for NonEmptyByteSlice {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'b> From<&'b NonEmptyByteSlice> for &'b [u8] {
+    fn from(val: &'b NonEmptyByteSlice) -> Self {
+        val.as_bytes()
+    }
+}
+
+impl PartialEq<[u8]> for NonEmptyByteSlice {
+    fn eq(&self, other: &[u8]) -> bool {
+        PartialEq::eq(self.as_bytes(), other)
+    }
+}
+
+impl PartialEq<NonEmptyByteSlice> for [u8] {
+    fn eq(&self, other: &NonEmptyByteSlice) -> bool {
+        PartialEq::eq(self, other.as_bytes())
+    }
+}
+
+/// Prints the type name, i.e. `NonEmptyByteSlice([1, 2, 3])`, to distinguish it from a plain byte slice.
+impl Debug for NonEmptyByteSlice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NonEmptyByteSlice")
+            .field(&self.as_bytes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::*};
+
+    #[test]
+    fn non_empty_byte_slice() {
+        let bytes = NonEmptyByteSlice::new(b"foo").unwrap();
+
+        assert_eq!(bytes, &b"foo"[..]);
+        assert_eq!(&b"foo"[..], bytes);
+        assert_eq!(bytes.as_bytes(), b"foo");
+        assert_eq!(bytes.len(), 3);
+        assert_eq!(bytes.len_nonzero().get(), 3);
+        assert_eq!(bytes.first(), b'f');
+        assert_eq!(bytes.last(), b'o');
+        assert_eq!(&bytes[1..], b"oo");
+
+        assert!(NonEmptyByteSlice::new(b"").is_none());
+
+        assert_eq!(format!("{:?}", bytes), "NonEmptyByteSlice([102, 111, 111])");
+
+        let foo = ne_str!("fö");
+        assert_eq!(foo.as_ne_bytes(), "fö".as_bytes());
+        assert_eq!(foo.as_ne_bytes().first(), foo.first_byte());
+        assert_eq!(foo.as_ne_bytes().last(), 0xb6);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "tried to create a non-empty byte slice from an empty source")]
+    fn new_unchecked_panic() {
+        let _ = unsafe { NonEmptyByteSlice::new_unchecked(b"") };
+    }
+}
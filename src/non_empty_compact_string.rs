@@ -0,0 +1,322 @@
+use {
+    crate::*,
+    alloc::string::String,
+    compact_str::CompactString,
+    core::{
+        borrow::Borrow,
+        cmp::PartialEq,
+        fmt::{Display, Formatter},
+        num::NonZeroUsize,
+        ops::Deref,
+    },
+    miniunchecked::*,
+};
+
+/// A non-empty [`CompactString`], which stores short strings inline, without allocating.
+///
+/// An alternative to the [`NonEmptyString`] for memory-heavy workloads with many short strings.
+/// Converts to / from the [`NonEmptyString`]; conversions may (re)allocate.
+///
+/// Hashes and compares exactly like the wrapped [`CompactString`] / [`str`],
+/// so it may be looked up in hash maps and sets by a `&str` key.
+///
+/// This is a separate type, rather than the backing storage of the [`NonEmptyString`] when the `compact_str` feature is enabled,
+/// so that the feature stays additive: switching the storage would change the [`NonEmptyString`]'s
+/// layout and [`String`]-typed API (e.g. [`NonEmptyString::inner`]) for every crate in the dependency graph.
+#[repr(transparent)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct NonEmptyCompactString(CompactString);
+
+impl NonEmptyCompactString {
+    /// Tries to create a [`NonEmptyCompactString`] from the string `s`.
+    /// Returns `None` if the string `s` is empty.
+    pub fn new(s: CompactString) -> Option<Self> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(Self(s))
+        }
+    }
+
+    /// Creates a [`NonEmptyCompactString`] from the string `s`
+    /// without checking if it is empty.
+    ///
+    /// # Safety
+    /// The caller guarantees the string `s` is not empty.
+    /// Passing an empty string is undefined behaviour.
+    ///
+    /// # Panics
+    /// In debug configuration only, panics if `s` is empty.
+    pub unsafe fn new_unchecked(s: CompactString) -> Self {
+        debug_assert!(
+            !s.is_empty(),
+            "tried to create a non-empty string from an empty source"
+        );
+        Self(s)
+    }
+
+    /// Creates a [`NonEmptyCompactString`] from the [`non-empty string slice`](NonEmptyStr) `s`.
+    pub fn from_ne_str(s: &NonEmptyStr) -> Self {
+        Self(CompactString::new(s.as_str()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn as_ne_str(&self) -> &NonEmptyStr {
+        unsafe { NonEmptyStr::new_unchecked(self.as_str()) }
+    }
+
+    pub fn inner(&self) -> &CompactString {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> CompactString {
+        self.0
+    }
+
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        unsafe {
+            NonZeroUsize::new(self.0.len())
+                .unwrap_unchecked_dbg_msg("non-empty strings have non-zero length")
+        }
+    }
+
+    /// Returns the length of this [`NonEmptyCompactString`] in bytes.
+    /// Never returns `0`; see also [`len_nonzero`](NonEmptyCompactString::len_nonzero).
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the string is stored on the heap, `false` if it is stored inline.
+    ///
+    /// See [`CompactString::is_heap_allocated`].
+    pub fn is_heap_allocated(&self) -> bool {
+        self.0.is_heap_allocated()
+    }
+
+    /// Appends the string slice `s` to the end of this [`NonEmptyCompactString`].
+    pub fn push_str(&mut self, s: &str) {
+        self.0.push_str(s)
+    }
+
+    /// Appends the char `c` to the end of this [`NonEmptyCompactString`].
+    pub fn push(&mut self, c: char) {
+        self.0.push(c)
+    }
+}
+
+impl Deref for NonEmptyCompactString {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ne_str()
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonEmptyCompactString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_ne_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyCompactString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<NonEmptyStr> for NonEmptyCompactString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_ne_str()
+    }
+}
+
+impl Borrow<str> for NonEmptyCompactString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyCompactString {
+    fn from(s: &NonEmptyStr) -> Self {
+        Self::from_ne_str(s)
+    }
+}
+
+/// Stores short strings inline, otherwise reuses the [`String`]'s allocation.
+impl From<NonEmptyString> for NonEmptyCompactString {
+    fn from(s: NonEmptyString) -> Self {
+        Self(CompactString::from(s.into_inner()))
+    }
+}
+
+impl From<NonEmptyCompactString> for NonEmptyString {
+    fn from(s: NonEmptyCompactString) -> Self {
+        unsafe { NonEmptyString::new_unchecked(s.0.into_string()) }
+    }
+}
+
+impl From<NonEmptyCompactString> for CompactString {
+    fn from(s: NonEmptyCompactString) -> Self {
+        s.into_inner()
+    }
+}
+
+impl From<NonEmptyCompactString> for String {
+    fn from(s: NonEmptyCompactString) -> Self {
+        s.0.into_string()
+    }
+}
+
+impl TryFrom<CompactString> for NonEmptyCompactString {
+    type Error = CompactString;
+
+    fn try_from(s: CompactString) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            Err(s)
+        } else {
+            Ok(Self(s))
+        }
+    }
+}
+
+// Comparsions.
+
+// <str>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<str> for NonEmptyCompactString {
+    fn eq(&self, other: &str) -> bool {
+        PartialEq::eq(self.as_str(), other)
+    }
+}
+
+impl PartialEq<&str> for NonEmptyCompactString {
+    fn eq(&self, other: &&str) -> bool {
+        PartialEq::eq(self.as_str(), *other)
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyCompactString> for str {
+    fn eq(&self, other: &NonEmptyCompactString) -> bool {
+        PartialEq::eq(self, other.as_str())
+    }
+}
+
+impl PartialEq<NonEmptyCompactString> for &str {
+    fn eq(&self, other: &NonEmptyCompactString) -> bool {
+        PartialEq::eq(*self, other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <NonEmptyStr>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<NonEmptyStr> for NonEmptyCompactString {
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ne_str(), other)
+    }
+}
+
+impl PartialEq<&NonEmptyStr> for NonEmptyCompactString {
+    fn eq(&self, other: &&NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ne_str(), *other)
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyCompactString> for NonEmptyStr {
+    fn eq(&self, other: &NonEmptyCompactString) -> bool {
+        PartialEq::eq(self, other.as_ne_str())
+    }
+}
+
+impl PartialEq<NonEmptyCompactString> for &NonEmptyStr {
+    fn eq(&self, other: &NonEmptyCompactString) -> bool {
+        PartialEq::eq(*self, other.as_ne_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+impl Display for NonEmptyCompactString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::HashSet};
+
+    fn cmp(nes: &NonEmptyCompactString, s: &str) {
+        assert_eq!(nes, s);
+        assert_eq!(s, nes);
+        assert_eq!(nes.as_str(), s);
+        assert_eq!(nes.as_ne_str(), s);
+        assert_eq!(nes.deref(), s);
+        assert_eq!(nes.len(), s.len());
+    }
+
+    #[test]
+    fn non_empty_compact_string() {
+        let foo = NonEmptyCompactString::new(CompactString::new("foo")).unwrap();
+        cmp(&foo, "foo");
+        assert!(!foo.is_heap_allocated());
+
+        assert!(NonEmptyCompactString::new(CompactString::default()).is_none());
+        assert!(NonEmptyCompactString::try_from(CompactString::default()).is_err());
+
+        assert_eq!(foo, ne_str!("foo"));
+        assert_eq!(ne_str!("foo"), foo);
+        assert_eq!(format!("{}", foo), "foo");
+        assert_eq!(format!("{:?}", foo), r#"NonEmptyCompactString("foo")"#);
+
+        let mut foo = foo;
+        foo.push_str("bar");
+        foo.push('!');
+        cmp(&foo, "foobar!");
+    }
+
+    #[test]
+    fn conversions() {
+        let long = "a string long enough to not be stored inline";
+
+        for s in ["foo", long] {
+            let ne_string = NonEmptyString::new(s.to_owned()).unwrap();
+
+            let compact = NonEmptyCompactString::from(ne_string.clone());
+            cmp(&compact, s);
+            assert_eq!(compact.is_heap_allocated(), s == long);
+
+            cmp(&NonEmptyCompactString::from(ne_string.as_ne_str()), s);
+
+            assert_eq!(
+                <NonEmptyString as From<_>>::from(compact.clone()),
+                ne_string
+            );
+            assert_eq!(String::from(compact.clone()), s);
+            assert_eq!(CompactString::from(compact), s);
+        }
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = HashSet::new();
+        set.insert(NonEmptyCompactString::from(ne_str!("foo")));
+
+        assert!(set.contains("foo"));
+        assert!(set.contains(ne_str!("foo")));
+        assert!(!set.contains("bar"));
+    }
+}
@@ -1,18 +1,41 @@
 use {
     crate::*,
-    miniunchecked::*,
-    std::{
-        borrow::{Cow, ToOwned},
-        cmp::PartialEq,
+    alloc::{
+        borrow::{Borrow, Cow, ToOwned},
+        boxed::Box,
+        rc::Rc,
+        string::String,
+        sync::Arc,
+    },
+    core::{
+        cmp::{Ordering, PartialEq},
         fmt::{Display, Formatter},
+        iter::Rev,
         num::NonZeroUsize,
-        ops::Deref,
+        ops::{
+            Add, Bound, Deref, Index, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+            RangeTo, RangeToInclusive,
+        },
+        str::{Bytes, CharIndices, Chars, FromStr, Lines},
     },
+    miniunchecked::*,
 };
 
 /// A non-empty UTF-8 string slice.
 ///
 /// This is the borrowed version, [`NonEmptyString`] is the owned version.
+///
+/// Hashes and compares exactly like the wrapped [`str`],
+/// so it may be looked up in hash maps and sets by a `&str` key.
+///
+/// Derefs to [`str`], which provides the search methods ([`str::find`], [`str::rfind`], [`str::contains`],
+/// [`str::starts_with`], [`str::ends_with`], [`str::matches`], etc.) with their generic pattern arguments.
+/// They are deliberately not mirrored as inherent methods, which would have to give up the generic patterns
+/// and would shadow the [`str`] versions.
+///
+/// Methods which take or return non-empty strings in place of plain ones have `ne` following the verb,
+/// e.g. [`split_ne`](NonEmptyStr::split_ne) or [`strip_ne_prefix`](NonEmptyStr::strip_ne_prefix),
+/// like [`as_ne_str`](NonEmptyString::as_ne_str).
 #[repr(transparent)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NonEmptyStr(str);
@@ -28,6 +51,25 @@ impl NonEmptyStr {
         }
     }
 
+    /// Tries to create a [`NonEmptyStr`] from the byte slice `bytes`.
+    /// Returns an error if `bytes` are empty or are not valid UTF-8.
+    pub fn from_utf8(bytes: &[u8]) -> Result<&Self, NonEmptyUtf8Error> {
+        if bytes.is_empty() {
+            Err(NonEmptyUtf8Error::Empty)
+        } else {
+            core::str::from_utf8(bytes)
+                .map(|s| unsafe { Self::new_unchecked(s) })
+                .map_err(NonEmptyUtf8Error::InvalidUtf8)
+        }
+    }
+
+    /// Tries to borrow a [`NonEmptyStr`] from the (borrowed or owned) string `s`, without allocating.
+    /// Returns `None` if the string `s` is empty.
+    #[allow(clippy::ptr_arg)]
+    pub fn from_cow<'s>(s: &'s Cow<'_, str>) -> Option<&'s Self> {
+        Self::new(s.as_ref())
+    }
+
     /// Creates a [`NonEmptyStr`] from the string slice `s`
     /// without checking if it is empty.
     ///
@@ -37,14 +79,27 @@ impl NonEmptyStr {
     ///
     /// # Panics
     /// In debug configuration only, panics if `s` is empty.
-    pub unsafe fn new_unchecked(s: &str) -> &Self {
-        debug_assert!(
-            !s.is_empty(),
-            "tried to create a non-empty string slice from an empty source"
-        );
+    pub const unsafe fn new_unchecked(s: &str) -> &Self {
+        if cfg!(debug_assertions) && s.is_empty() {
+            panic!("tried to create a non-empty string slice from an empty source");
+        }
         &*(s as *const str as *const _)
     }
 
+    /// Creates a [`NonEmptyStr`] from the string slice `s`.
+    ///
+    /// Intended for use in `const` contexts (e.g. with string literals),
+    /// where an empty string `s` results in a compile-time error.
+    ///
+    /// # Panics
+    /// Panics if `s` is empty.
+    pub const fn new_const(s: &str) -> &Self {
+        if s.is_empty() {
+            panic!("tried to create a non-empty string slice from an empty source");
+        }
+        unsafe { Self::new_unchecked(s) }
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -55,8 +110,511 @@ impl NonEmptyStr {
                 .unwrap_unchecked_dbg_msg("non-empty strings have non-zero length")
         }
     }
+
+    /// Returns the number of chars in this [`NonEmptyStr`].
+    /// Unlike [`len_nonzero`](NonEmptyStr::len_nonzero), this iterates over the whole string.
+    pub fn char_count(&self) -> NonZeroUsize {
+        unsafe {
+            NonZeroUsize::new(self.0.chars().count())
+                .unwrap_unchecked_dbg_msg("non-empty strings have at least one char")
+        }
+    }
+
+    /// Returns the length of this [`NonEmptyStr`] in bytes.
+    /// Never returns `0`; see also [`len_nonzero`](NonEmptyStr::len_nonzero).
+    ///
+    /// See [`str::len`].
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the byte `index` lies on a char boundary of this [`NonEmptyStr`]
+    /// (or is equal to its length).
+    ///
+    /// See [`str::is_char_boundary`].
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        self.0.is_char_boundary(index)
+    }
+
+    /// Returns the UTF-8 bytes of this [`NonEmptyStr`].
+    /// The returned byte slice is never empty.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Returns the UTF-8 bytes of this [`NonEmptyStr`] as a [`NonEmptyByteSlice`].
+    pub fn as_ne_bytes(&self) -> &NonEmptyByteSlice {
+        unsafe { NonEmptyByteSlice::new_unchecked(self.0.as_bytes()) }
+    }
+
+    /// Returns an iterator over the bytes of this [`NonEmptyStr`].
+    /// The iterator yields at least one byte.
+    ///
+    /// See [`str::bytes`].
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.0.bytes()
+    }
+
+    /// Returns the first byte of this [`NonEmptyStr`].
+    pub fn first_byte(&self) -> u8 {
+        unsafe {
+            *self
+                .as_bytes()
+                .first()
+                .unwrap_unchecked_dbg_msg("non-empty strings have at least one byte")
+        }
+    }
+
+    /// Returns the byte of this [`NonEmptyStr`] at the index `n`,
+    /// or `None` if `n` is out of bounds.
+    ///
+    /// See also [`first_byte`](NonEmptyStr::first_byte), which never fails.
+    pub fn nth_byte(&self, n: usize) -> Option<u8> {
+        self.as_bytes().get(n).copied()
+    }
+
+    /// Returns an iterator over the chars of this [`NonEmptyStr`].
+    /// The iterator yields at least one char.
+    ///
+    /// See [`str::chars`].
+    pub fn chars(&self) -> Chars<'_> {
+        self.0.chars()
+    }
+
+    /// Returns an iterator over the chars of this [`NonEmptyStr`] and their byte positions.
+    /// The iterator yields at least one char.
+    ///
+    /// See [`str::char_indices`].
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        self.0.char_indices()
+    }
+
+    /// Returns the first char of this [`NonEmptyStr`].
+    pub fn first_char(&self) -> char {
+        unsafe {
+            self.0
+                .chars()
+                .next()
+                .unwrap_unchecked_dbg_msg("non-empty strings have at least one char")
+        }
+    }
+
+    /// Returns the first char of this [`NonEmptyStr`] and the (possibly empty) rest of the string.
+    pub fn split_first_char(&self) -> (char, &str) {
+        let first = self.first_char();
+        (first, unsafe { self.0.get_unchecked(first.len_utf8()..) })
+    }
+
+    /// Returns the last char of this [`NonEmptyStr`].
+    pub fn last_char(&self) -> char {
+        unsafe {
+            self.0
+                .chars()
+                .next_back()
+                .unwrap_unchecked_dbg_msg("non-empty strings have at least one char")
+        }
+    }
+
+    /// Returns an iterator over the chars of this [`NonEmptyStr`] in reverse order,
+    /// starting with the [`last_char`](NonEmptyStr::last_char).
+    /// The iterator yields at least one char.
+    pub fn chars_rev(&self) -> Rev<Chars<'_>> {
+        self.0.chars().rev()
+    }
+
+    /// Returns a non-empty subslice of this [`NonEmptyStr`] in the byte `range`.
+    ///
+    /// Returns `None` if the `range` is empty, out of bounds or does not lie on char boundaries.
+    ///
+    /// See [`str::get`].
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<&NonEmptyStr> {
+        self.0
+            .get((range.start_bound().cloned(), range.end_bound().cloned()))
+            .and_then(NonEmptyStr::new)
+    }
+
+    /// Divides this [`NonEmptyStr`] into two at the byte index `mid`,
+    /// returning the non-empty first half and the (possibly empty) second half.
+    ///
+    /// # Panics
+    /// Panics if `mid` is larger than the string's length, or if it does not lie on a char boundary.
+    ///
+    /// See [`str::split_at`].
+    pub fn split_at(&self, mid: NonZeroUsize) -> (&NonEmptyStr, &str) {
+        let (first, second) = self.0.split_at(mid.get());
+        (unsafe { NonEmptyStr::new_unchecked(first) }, second)
+    }
+
+    /// Returns this [`NonEmptyStr`] with leading and trailing whitespace removed,
+    /// or `None` if the string only contains whitespace.
+    ///
+    /// See [`str::trim`].
+    pub fn trim(&self) -> Option<&NonEmptyStr> {
+        NonEmptyStr::new(self.0.trim())
+    }
+
+    /// Returns this [`NonEmptyStr`] with leading whitespace removed,
+    /// or `None` if the string only contains whitespace.
+    ///
+    /// See [`str::trim_start`].
+    pub fn trim_start(&self) -> Option<&NonEmptyStr> {
+        NonEmptyStr::new(self.0.trim_start())
+    }
+
+    /// Returns this [`NonEmptyStr`] with trailing whitespace removed,
+    /// or `None` if the string only contains whitespace.
+    ///
+    /// See [`str::trim_end`].
+    pub fn trim_end(&self) -> Option<&NonEmptyStr> {
+        NonEmptyStr::new(self.0.trim_end())
+    }
+
+    /// Returns an iterator over the lines of this [`NonEmptyStr`].
+    /// Lines may be empty.
+    ///
+    /// See [`str::lines`].
+    pub fn lines(&self) -> Lines<'_> {
+        self.0.lines()
+    }
+
+    /// Returns an iterator over the non-empty lines of this [`NonEmptyStr`].
+    /// Empty lines are skipped; lines which only contain whitespace are not.
+    ///
+    /// Lines are split like in [`str::lines`] (i.e. on `\n` or `\r\n`).
+    pub fn lines_ne(&self) -> impl Iterator<Item = &NonEmptyStr> {
+        self.0
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| unsafe { NonEmptyStr::new_unchecked(l) })
+    }
+
+    /// Returns an iterator over the non-empty substrings of this [`NonEmptyStr`] separated by the char `pat`.
+    /// Empty substrings are skipped.
+    ///
+    /// See [`str::split`].
+    pub fn split_ne(&self, pat: char) -> impl Iterator<Item = &NonEmptyStr> {
+        self.split(pat).filter_map(NonEmptyStr::new)
+    }
+
+    /// Returns an iterator over the whitespace-separated substrings of this [`NonEmptyStr`].
+    /// Substrings are never empty.
+    ///
+    /// See [`str::split_whitespace`].
+    pub fn split_whitespace(&self) -> impl Iterator<Item = &NonEmptyStr> {
+        self.0
+            .split_whitespace()
+            .map(|s| unsafe { NonEmptyStr::new_unchecked(s) })
+    }
+
+    /// Returns an iterator over the ASCII whitespace-separated substrings of this [`NonEmptyStr`].
+    /// Substrings are never empty.
+    ///
+    /// See [`str::split_ascii_whitespace`].
+    pub fn split_ascii_whitespace(&self) -> impl Iterator<Item = &NonEmptyStr> {
+        self.0
+            .split_ascii_whitespace()
+            .map(|s| unsafe { NonEmptyStr::new_unchecked(s) })
+    }
+
+    /// Splits this [`NonEmptyStr`] on the first occurrence of the char `delim`,
+    /// returning the substrings before and after it, or `None` if `delim` does not occur.
+    ///
+    /// Each substring is returned as a [`NonEmptyStr`], or `None` if it is empty
+    /// (i.e. if `delim` is at the start / end of the string).
+    ///
+    /// See [`str::split_once`].
+    pub fn split_ne_once(
+        &self,
+        delim: char,
+    ) -> Option<(Option<&NonEmptyStr>, Option<&NonEmptyStr>)> {
+        self.split_once(delim)
+            .map(|(first, second)| (NonEmptyStr::new(first), NonEmptyStr::new(second)))
+    }
+
+    /// Splits this [`NonEmptyStr`] on the last occurrence of the char `delim`,
+    /// returning the substrings before and after it, or `None` if `delim` does not occur.
+    ///
+    /// Each substring is returned as a [`NonEmptyStr`], or `None` if it is empty
+    /// (i.e. if `delim` is at the start / end of the string).
+    ///
+    /// See [`str::rsplit_once`].
+    pub fn rsplit_ne_once(
+        &self,
+        delim: char,
+    ) -> Option<(Option<&NonEmptyStr>, Option<&NonEmptyStr>)> {
+        self.rsplit_once(delim)
+            .map(|(first, second)| (NonEmptyStr::new(first), NonEmptyStr::new(second)))
+    }
+
+    /// Returns the byte index of the first occurrence of this [`NonEmptyStr`] in the `haystack`,
+    /// or `None` if the `haystack` does not contain it.
+    ///
+    /// The inverse of [`str::find`], for when the non-empty string is the needle.
+    /// Named `find_in` rather than `find` so as not to shadow [`str::find`],
+    /// which remains available via `Deref` for searching within this string.
+    pub fn find_in(&self, haystack: &str) -> Option<usize> {
+        haystack.find(self.as_str())
+    }
+
+    /// Returns the rest of this [`NonEmptyStr`] after the non-empty `prefix`,
+    /// or `None` if this string does not start with the `prefix`.
+    ///
+    /// Unlike [`strip_prefix`](str::strip_prefix), a match always strips at least one byte.
+    /// The returned string slice may still be empty if the `prefix` matched the whole string;
+    /// map it with [`NonEmptyStr::new`] for an `Option<Option<&NonEmptyStr>>` which keeps the two cases apart.
+    pub fn strip_ne_prefix(&self, prefix: &NonEmptyStr) -> Option<&str> {
+        self.strip_prefix(prefix.as_str())
+    }
+
+    /// Returns the rest of this [`NonEmptyStr`] before the non-empty `suffix`,
+    /// or `None` if this string does not end with the `suffix`.
+    ///
+    /// Unlike [`strip_suffix`](str::strip_suffix), a match always strips at least one byte.
+    /// The returned string slice may still be empty if the `suffix` matched the whole string;
+    /// map it with [`NonEmptyStr::new`] for an `Option<Option<&NonEmptyStr>>` which keeps the two cases apart.
+    pub fn strip_ne_suffix(&self, suffix: &NonEmptyStr) -> Option<&str> {
+        self.strip_suffix(suffix.as_str())
+    }
+
+    /// Parses this [`NonEmptyStr`] into another type.
+    ///
+    /// See [`str::parse`].
+    pub fn parse<F: FromStr>(&self) -> Result<F, F::Err> {
+        self.0.parse()
+    }
+
+    /// Copies this [`NonEmptyStr`] into a new boxed string slice,
+    /// which has no excess capacity.
+    pub fn to_boxed_str(&self) -> Box<str> {
+        self.as_str().into()
+    }
+
+    /// Copies this [`NonEmptyStr`] into a new [`String`] with capacity exactly equal to its length.
+    pub fn to_string_exact(&self) -> String {
+        // `Box<str>` has no excess capacity, and neither does the `String` it converts into.
+        self.to_boxed_str().into_string()
+    }
+
+    /// Returns `true` if this [`NonEmptyStr`] consists of exactly the single char `c`.
+    pub(crate) fn eq_char(&self, c: char) -> bool {
+        self.0.len() == c.len_utf8() && self.0.starts_with(c)
+    }
+
+    /// Returns the uppercase equivalent of this [`NonEmptyStr`] as a new [`NonEmptyString`].
+    ///
+    /// See [`str::to_uppercase`].
+    pub fn to_uppercase(&self) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.to_uppercase()) }
+    }
+
+    /// Returns the lowercase equivalent of this [`NonEmptyStr`] as a new [`NonEmptyString`].
+    ///
+    /// See [`str::to_lowercase`].
+    pub fn to_lowercase(&self) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.to_lowercase()) }
+    }
+
+    /// Returns a copy of this [`NonEmptyStr`] as a new [`NonEmptyString`]
+    /// where each ASCII character is mapped to its uppercase equivalent.
+    ///
+    /// See [`str::to_ascii_uppercase`].
+    pub fn to_ascii_uppercase(&self) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.to_ascii_uppercase()) }
+    }
+
+    /// Returns a copy of this [`NonEmptyStr`] as a new [`NonEmptyString`]
+    /// where each ASCII character is mapped to its lowercase equivalent.
+    ///
+    /// See [`str::to_ascii_lowercase`].
+    pub fn to_ascii_lowercase(&self) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.to_ascii_lowercase()) }
+    }
+
+    /// Returns `true` if this [`NonEmptyStr`] and the `other` string are an ASCII case-insensitive match.
+    ///
+    /// The `other` string may also be a [`NonEmptyStr`] / [`NonEmptyString`] (via deref coercion).
+    ///
+    /// See [`str::eq_ignore_ascii_case`].
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+
+    /// Returns a copy of this [`NonEmptyStr`] as a new [`NonEmptyString`]
+    /// with the first char converted to uppercase and the rest of the string unchanged.
+    ///
+    /// The first char may map to multiple chars (e.g. `'ß'` to `"SS"`); see [`char::to_uppercase`].
+    pub fn capitalize_first(&self) -> NonEmptyString {
+        let (first, rest) = self.split_first_char();
+
+        let mut result = String::with_capacity(self.0.len());
+        result.extend(first.to_uppercase());
+        result.push_str(rest);
+
+        unsafe { NonEmptyString::new_unchecked(result) }
+    }
+
+    /// Returns `true` if all chars in this [`NonEmptyStr`] are ASCII.
+    ///
+    /// See [`str::is_ascii`].
+    pub fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+
+    /// Returns `true` if all chars in this [`NonEmptyStr`] are ASCII alphanumeric
+    /// (i.e. `a-z`, `A-Z` or `0-9`).
+    ///
+    /// See [`char::is_ascii_alphanumeric`].
+    pub fn is_ascii_alphanumeric_all(&self) -> bool {
+        self.0.bytes().all(|b| b.is_ascii_alphanumeric())
+    }
+
+    /// Returns a copy of this [`NonEmptyStr`] as a new [`NonEmptyString`]
+    /// with leading and trailing whitespace removed, and each internal run of whitespace
+    /// replaced with a single space (`' '`).
+    /// Returns `None` if the string only contains whitespace.
+    ///
+    /// Whitespace is defined by [`char::is_whitespace`] (i.e. includes Unicode whitespace).
+    pub fn collapse_whitespace(&self) -> Option<NonEmptyString> {
+        let mut words = self.split_whitespace();
+        let mut result = NonEmptyString::from(words.next()?);
+
+        for word in words {
+            result.push(' ');
+            result.push_str(word);
+        }
+
+        Some(result)
+    }
+
+    /// Creates a new [`NonEmptyString`] by appending the `other` [`NonEmptyStr`] to this one.
+    pub fn concat(&self, other: &NonEmptyStr) -> NonEmptyString {
+        let mut result = String::with_capacity(self.0.len() + other.0.len());
+        result.push_str(self.as_str());
+        result.push_str(other.as_str());
+        unsafe { NonEmptyString::new_unchecked(result) }
+    }
+
+    /// Replaces all matches of `from` in this [`NonEmptyStr`] with `to`, returning the result as a new [`NonEmptyString`].
+    ///
+    /// Returns `None` if the result is empty (e.g. if the whole string was replaced with an empty string).
+    /// Use [`replace_ne`](NonEmptyStr::replace_ne) if the replacement string is known to be non-empty.
+    ///
+    /// See [`str::replace`].
+    pub fn replace(&self, from: &str, to: &str) -> Option<NonEmptyString> {
+        NonEmptyString::new(self.0.replace(from, to))
+    }
+
+    /// Replaces all matches of `from` in this [`NonEmptyStr`] with the non-empty `to`,
+    /// returning the result as a new [`NonEmptyString`].
+    ///
+    /// See [`str::replace`].
+    pub fn replace_ne(&self, from: &str, to: &NonEmptyStr) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.replace(from, to)) }
+    }
+
+    /// Creates a new [`NonEmptyString`] by repeating this [`NonEmptyStr`] `n` times.
+    ///
+    /// See [`str::repeat`].
+    pub fn repeat(&self, n: NonZeroUsize) -> NonEmptyString {
+        unsafe { NonEmptyString::new_unchecked(self.0.repeat(n.get())) }
+    }
+
+    /// Replaces the contents of the `out` [`NonEmptyString`] with this [`NonEmptyStr`] repeated `n` times,
+    /// reusing its allocation.
+    ///
+    /// See [`repeat`](NonEmptyStr::repeat).
+    pub fn repeat_into(&self, n: NonZeroUsize, out: &mut NonEmptyString) {
+        let len = self
+            .0
+            .len()
+            .checked_mul(n.get())
+            .expect("capacity overflow");
+
+        let out = unsafe { out.as_mut_string() };
+        // Reserve before clearing, so that a panic leaves the `out` string unchanged (and non-empty).
+        out.reserve(len.saturating_sub(out.len()));
+        out.clear();
+
+        for _ in 0..n.get() {
+            out.push_str(self.as_str());
+        }
+    }
+
+    /// Hashes this [`NonEmptyStr`] to a `u32` using the FNV1a (32b) hash.
+    ///
+    /// See [`str_hash_fnv1a`].
+    pub const fn hash_fnv1a(&self) -> u32 {
+        str_hash_fnv1a(&self.0)
+    }
+
+    /// Hashes this [`NonEmptyStr`] to a `u64` using the FNV1a (64b) hash.
+    ///
+    /// See [`str_hash_fnv1a_64`].
+    pub const fn hash_fnv1a_64(&self) -> u64 {
+        str_hash_fnv1a_64(&self.0)
+    }
+
+    /// Hashes this [`NonEmptyStr`] to a `u64` using the Rust's default hasher.
+    ///
+    /// See [`str_hash_default`].
+    #[cfg(feature = "std")]
+    pub fn hash_default(&self) -> u64 {
+        str_hash_default(&self.0)
+    }
+}
+
+/// Creates a `&'static` [`NonEmptyStr`] from a string literal.
+///
+/// The literal is validated at compile time - an empty literal fails the build.
+///
+/// ```
+/// use ministr::{ne_str, NonEmptyStr};
+///
+/// const KEY: &NonEmptyStr = ne_str!("player.health");
+/// assert_eq!(KEY, "player.health");
+/// ```
+///
+/// ```compile_fail
+/// let _ = ministr::ne_str!("");
+/// ```
+#[macro_export]
+macro_rules! ne_str {
+    ($s:literal) => {{
+        const NE_STR: &'static $crate::NonEmptyStr = $crate::NonEmptyStr::new_const($s);
+        NE_STR
+    }};
+}
+
+/// A full range slice of a [`NonEmptyStr`] is never empty.
+impl Index<RangeFull> for NonEmptyStr {
+    type Output = NonEmptyStr;
+
+    fn index(&self, _: RangeFull) -> &Self::Output {
+        self
+    }
+}
+
+/// Other ranges may produce an empty string slice.
+macro_rules! impl_index {
+    ($range:ty) => {
+        impl Index<$range> for NonEmptyStr {
+            type Output = str;
+
+            fn index(&self, index: $range) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+    };
 }
 
+impl_index!(Range<usize>);
+impl_index!(RangeFrom<usize>);
+impl_index!(RangeInclusive<usize>);
+impl_index!(RangeTo<usize>);
+impl_index!(RangeToInclusive<usize>);
+impl_index!((Bound<usize>, Bound<usize>));
+
 impl Deref for NonEmptyStr {
     type Target = str;
 
@@ -71,12 +629,38 @@ impl AsRef<str> for &NonEmptyStr {
     }
 }
 
+impl AsRef<[u8]> for NonEmptyStr {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl AsRef<NonEmptyStr> for &NonEmptyStr {
     fn as_ref(&self) -> &NonEmptyStr {
         self
     }
 }
 
+impl Borrow<str> for NonEmptyStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for &NonEmptyStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Add<&NonEmptyStr> for &NonEmptyStr {
+    type Output = NonEmptyString;
+
+    fn add(self, rhs: &NonEmptyStr) -> Self::Output {
+        self.concat(rhs)
+    }
+}
+
 impl ToOwned for NonEmptyStr {
     type Owned = NonEmptyString;
 
@@ -85,21 +669,37 @@ impl ToOwned for NonEmptyStr {
     }
 }
 
-// Fallible conversions from string slices and owned strings.
+// Fallible conversions from string slices, owned strings and byte slices.
 ////////////////////////////////////////////////////////////
 impl<'s> TryFrom<&'s str> for &'s NonEmptyStr {
-    type Error = ();
+    type Error = EmptyStringError;
 
     fn try_from(s: &'s str) -> Result<Self, Self::Error> {
-        NonEmptyStr::new(s).ok_or(())
+        NonEmptyStr::new(s).ok_or(EmptyStringError)
     }
 }
 
 impl<'s> TryFrom<&'s String> for &'s NonEmptyStr {
-    type Error = ();
+    type Error = EmptyStringError;
 
     fn try_from(s: &'s String) -> Result<Self, Self::Error> {
-        NonEmptyStr::new(s).ok_or(())
+        NonEmptyStr::new(s).ok_or(EmptyStringError)
+    }
+}
+
+impl<'s, 'c> TryFrom<&'s Cow<'c, str>> for &'s NonEmptyStr {
+    type Error = EmptyStringError;
+
+    fn try_from(s: &'s Cow<'c, str>) -> Result<Self, Self::Error> {
+        NonEmptyStr::from_cow(s).ok_or(EmptyStringError)
+    }
+}
+
+impl<'s> TryFrom<&'s [u8]> for &'s NonEmptyStr {
+    type Error = NonEmptyUtf8Error;
+
+    fn try_from(bytes: &'s [u8]) -> Result<Self, Self::Error> {
+        NonEmptyStr::from_utf8(bytes)
     }
 }
 ////////////////////////////////////////////////////////////
@@ -128,6 +728,13 @@ impl<'s> From<&'s NonEmptyStr> for String {
     }
 }
 
+/// The boxed string slice has no excess capacity.
+impl<'s> From<&'s NonEmptyStr> for Box<str> {
+    fn from(val: &'s NonEmptyStr) -> Self {
+        val.to_boxed_str()
+    }
+}
+
 impl<'s> From<&'s NonEmptyStr> for Cow<'s, str> {
     fn from(val: &'s NonEmptyStr) -> Self {
         Cow::Borrowed(val.as_str())
@@ -139,6 +746,20 @@ impl<'s> From<&'s NonEmptyStr> for Cow<'s, NonEmptyStr> {
         Cow::Borrowed(val)
     }
 }
+
+impl<'s> From<&'s NonEmptyStr> for Arc<NonEmptyStr> {
+    fn from(val: &'s NonEmptyStr) -> Self {
+        let arc: Arc<str> = val.as_str().into();
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const NonEmptyStr) }
+    }
+}
+
+impl<'s> From<&'s NonEmptyStr> for Rc<NonEmptyStr> {
+    fn from(val: &'s NonEmptyStr) -> Self {
+        let rc: Rc<str> = val.as_str().into();
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const NonEmptyStr) }
+    }
+}
 ////////////////////////////////////////////////////////////
 
 // Comparsions.
@@ -335,8 +956,162 @@ impl PartialEq<NonEmptyString> for &NonEmptyStr {
 }
 ////////////////////////////////////////////////////////////
 
-impl<'s> Display for &'s NonEmptyStr {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+// <Cow<NonEmptyStr>>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<Cow<'_, NonEmptyStr>> for NonEmptyStr {
+    fn eq(&self, other: &Cow<'_, NonEmptyStr>) -> bool {
+        PartialEq::eq(self, other.as_ref())
+    }
+}
+
+impl PartialEq<Cow<'_, NonEmptyStr>> for &NonEmptyStr {
+    fn eq(&self, other: &Cow<'_, NonEmptyStr>) -> bool {
+        PartialEq::eq(*self, other.as_ref())
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyStr> for Cow<'_, NonEmptyStr> {
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ref(), other)
+    }
+}
+
+impl PartialEq<&NonEmptyStr> for Cow<'_, NonEmptyStr> {
+    fn eq(&self, other: &&NonEmptyStr) -> bool {
+        PartialEq::eq(self.as_ref(), *other)
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <char>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<char> for NonEmptyStr {
+    fn eq(&self, other: &char) -> bool {
+        self.eq_char(*other)
+    }
+}
+
+impl PartialEq<char> for &NonEmptyStr {
+    fn eq(&self, other: &char) -> bool {
+        self.eq_char(*other)
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyStr> for char {
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        other.eq_char(*self)
+    }
+}
+
+impl PartialEq<&NonEmptyStr> for char {
+    fn eq(&self, other: &&NonEmptyStr) -> bool {
+        other.eq_char(*self)
+    }
+}
+////////////////////////////////////////////////////////////
+
+// Ordering comparisons.
+
+// <str>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialOrd<str> for NonEmptyStr {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other)
+    }
+}
+
+impl PartialOrd<&str> for NonEmptyStr {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), *other)
+    }
+}
+
+impl PartialOrd<str> for &NonEmptyStr {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other)
+    }
+}
+
+// Reverse
+
+impl PartialOrd<NonEmptyStr> for str {
+    fn partial_cmp(&self, other: &NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self, other.as_str())
+    }
+}
+
+impl PartialOrd<&NonEmptyStr> for str {
+    fn partial_cmp(&self, other: &&NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self, other.as_str())
+    }
+}
+
+impl PartialOrd<NonEmptyStr> for &str {
+    fn partial_cmp(&self, other: &NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(*self, other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <String>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialOrd<String> for NonEmptyStr {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<&String> for NonEmptyStr {
+    fn partial_cmp(&self, other: &&String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<String> for &NonEmptyStr {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+// Reverse
+
+impl PartialOrd<NonEmptyStr> for String {
+    fn partial_cmp(&self, other: &NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<&NonEmptyStr> for String {
+    fn partial_cmp(&self, other: &&NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<NonEmptyStr> for &String {
+    fn partial_cmp(&self, other: &NonEmptyStr) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+impl Display for NonEmptyStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.as_str().fmt(f)
     }
 }
@@ -345,6 +1120,7 @@ impl<'s> Display for &'s NonEmptyStr {
 mod tests {
     use super::*;
 
+    #[allow(clippy::redundant_slicing)]
     fn cmp(nes: &NonEmptyStr, s: &str) {
         assert_eq!(nes, s);
         assert_eq!(s, nes);
@@ -411,10 +1187,10 @@ mod tests {
             use std::convert::TryInto;
 
             let ne_foo: Result<&NonEmptyStr, _> = "".try_into();
-            assert!(ne_foo.is_err());
+            assert_eq!(ne_foo, Err(EmptyStringError));
 
             let ne_foo = <&NonEmptyStr as TryFrom<&str>>::try_from("");
-            assert!(ne_foo.is_err());
+            assert_eq!(ne_foo, Err(EmptyStringError));
         }
 
         // try from non-empty `String`
@@ -435,13 +1211,579 @@ mod tests {
             let empty_str = "".to_owned();
 
             let ne_foo: Result<&NonEmptyStr, _> = (&empty_str).try_into();
-            assert!(ne_foo.is_err());
+            assert_eq!(ne_foo, Err(EmptyStringError));
 
             let ne_foo = <&NonEmptyStr as TryFrom<&String>>::try_from(&empty_str);
-            assert!(ne_foo.is_err());
+            assert_eq!(ne_foo, Err(EmptyStringError));
         }
     }
 
+    #[test]
+    fn new_const() {
+        const FOO: &NonEmptyStr = NonEmptyStr::new_const("foo");
+        cmp(FOO, "foo");
+
+        let foo = NonEmptyStr::new_const("foo");
+        cmp(foo, "foo");
+    }
+
+    #[test]
+    #[allow(clippy::op_ref, clippy::cmp_owned)]
+    fn ordering() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+        let foo_str = "foo".to_owned();
+
+        assert!(foo < "zzz");
+        assert!(foo > "aaa");
+        assert!(*foo <= *"foo");
+        assert!("zzz" > foo);
+        assert!(*"aaa" < *foo);
+        assert!("foo" >= *foo);
+
+        assert!(foo < "zzz".to_owned());
+        assert!(*foo > "aaa".to_owned());
+        assert!(*foo <= &foo_str);
+        assert!("zzz".to_owned() > foo);
+        assert!("aaa".to_owned() < *foo);
+        assert!(&foo_str >= *foo);
+
+        assert_eq!(foo.partial_cmp("foo"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn display() {
+        fn display<T: Display + ?Sized>(val: &T) -> String {
+            format!("{}", val)
+        }
+
+        let foo = NonEmptyStr::new("foo").unwrap();
+
+        assert_eq!(format!("{}", foo), "foo");
+        assert_eq!(format!("{:>5}", foo), "  foo");
+        assert_eq!(display(foo), "foo");
+        assert_eq!(display(&foo), "foo");
+    }
+
+    #[test]
+    fn debug() {
+        let foo = NonEmptyStr::new("f\"oo").unwrap();
+
+        assert_eq!(format!("{:?}", foo), r#"NonEmptyStr("f\"oo")"#);
+        assert_eq!(format!("{}", foo), r#"f"oo"#);
+    }
+
+    #[test]
+    fn ne_str_macro() {
+        const FOO: &NonEmptyStr = ne_str!("foo");
+        cmp(FOO, "foo");
+
+        let foo: &'static NonEmptyStr = ne_str!("foo");
+        cmp(foo, "foo");
+    }
+
+    #[test]
+    fn from_utf8() {
+        let foo = NonEmptyStr::from_utf8(b"foo").unwrap();
+        cmp(foo, "foo");
+
+        let foo: &NonEmptyStr = b"foo"[..].try_into().unwrap();
+        cmp(foo, "foo");
+
+        assert_eq!(NonEmptyStr::from_utf8(b""), Err(NonEmptyUtf8Error::Empty));
+        assert!(matches!(
+            NonEmptyStr::from_utf8(b"fo\xff"),
+            Err(NonEmptyUtf8Error::InvalidUtf8(_))
+        ));
+    }
+
+    #[test]
+    fn from_cow() {
+        let borrowed = Cow::Borrowed("foo");
+        cmp(NonEmptyStr::from_cow(&borrowed).unwrap(), "foo");
+
+        let owned: Cow<'_, str> = Cow::Owned("foo".to_owned());
+        cmp(NonEmptyStr::from_cow(&owned).unwrap(), "foo");
+
+        let foo: &NonEmptyStr = (&owned).try_into().unwrap();
+        cmp(foo, "foo");
+
+        assert!(NonEmptyStr::from_cow(&Cow::Borrowed("")).is_none());
+        assert!(NonEmptyStr::from_cow(&Cow::Owned(String::new())).is_none());
+        assert_eq!(
+            <&NonEmptyStr as TryFrom<_>>::try_from(&Cow::Borrowed("")),
+            Err(EmptyStringError)
+        );
+    }
+
+    #[test]
+    fn shared() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+
+        let arc: Arc<NonEmptyStr> = foo.into();
+        cmp(&arc, "foo");
+        let arc_clone = arc.clone();
+        cmp(&arc_clone, "foo");
+
+        let rc: Rc<NonEmptyStr> = foo.into();
+        cmp(&rc, "foo");
+        let rc_clone = rc.clone();
+        cmp(&rc_clone, "foo");
+    }
+
+    #[test]
+    fn exact_capacity() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+
+        let boxed = foo.to_boxed_str();
+        assert_eq!(&*boxed, "fö💡");
+
+        let boxed: Box<str> = foo.into();
+        assert_eq!(&*boxed, "fö💡");
+
+        let string = foo.to_string_exact();
+        assert_eq!(string, "fö💡");
+        assert_eq!(string.capacity(), string.len());
+    }
+
+    #[test]
+    fn bytes() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+        assert_eq!(foo.as_bytes(), b"foo");
+        assert_eq!(foo.first_byte(), b'f');
+
+        let bulb = NonEmptyStr::new("💡").unwrap();
+        assert_eq!(bulb.as_bytes(), "💡".as_bytes());
+        assert_eq!(bulb.first_byte(), 0xf0);
+        assert_eq!(bulb.bytes().collect::<Vec<_>>(), "💡".as_bytes());
+
+        assert_eq!(foo.nth_byte(0), Some(b'f'));
+        assert_eq!(foo.nth_byte(2), Some(b'o'));
+        assert_eq!(foo.nth_byte(3), None);
+        assert_eq!(bulb.nth_byte(3), Some(0xa1));
+    }
+
+    #[test]
+    fn as_ref_bytes() {
+        fn bytes<B: AsRef<[u8]> + ?Sized>(b: &B) -> &[u8] {
+            b.as_ref()
+        }
+
+        let foo = ne_str!("fö");
+        assert_eq!(bytes(foo), "fö".as_bytes());
+        assert_eq!(bytes(&foo), "fö".as_bytes());
+    }
+
+    #[test]
+    fn len() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+        assert_eq!(foo.len(), 7);
+        assert_eq!(foo.len(), foo.len_nonzero().get());
+
+        assert!(foo.is_char_boundary(0));
+        assert!(foo.is_char_boundary(1));
+        assert!(!foo.is_char_boundary(2));
+        assert!(foo.is_char_boundary(3));
+        assert!(!foo.is_char_boundary(4));
+        assert!(foo.is_char_boundary(7));
+        assert!(!foo.is_char_boundary(8));
+    }
+
+    #[test]
+    fn chars() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+
+        assert_eq!(foo.chars().collect::<Vec<_>>(), ['f', 'ö', '💡']);
+        assert_eq!(
+            foo.char_indices().collect::<Vec<_>>(),
+            [(0, 'f'), (1, 'ö'), (3, '💡')]
+        );
+    }
+
+    #[test]
+    fn char_count() {
+        assert_eq!(NonEmptyStr::new("fö💡").unwrap().char_count().get(), 3);
+        assert_eq!(NonEmptyStr::new("💡").unwrap().char_count().get(), 1);
+        assert_eq!(NonEmptyStr::new("foo").unwrap().char_count().get(), 3);
+    }
+
+    #[test]
+    fn first_char() {
+        let foo = NonEmptyStr::new("💡oo").unwrap();
+        assert_eq!(foo.first_char(), '💡');
+        assert_eq!(foo.split_first_char(), ('💡', "oo"));
+
+        let f = NonEmptyStr::new("f").unwrap();
+        assert_eq!(f.first_char(), 'f');
+        assert_eq!(f.split_first_char(), ('f', ""));
+    }
+
+    #[test]
+    fn last_char() {
+        let foo = NonEmptyStr::new("fo💡").unwrap();
+        assert_eq!(foo.last_char(), '💡');
+
+        let f = NonEmptyStr::new("f").unwrap();
+        assert_eq!(f.last_char(), 'f');
+
+        assert_eq!(foo.chars_rev().collect::<String>(), "💡of");
+        assert_eq!(foo.chars_rev().next(), Some(foo.last_char()));
+        assert_eq!(f.chars_rev().collect::<Vec<_>>(), ['f']);
+    }
+
+    #[test]
+    fn get() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+
+        assert_eq!(foo.get(..).unwrap(), "fö💡");
+        assert_eq!(foo.get(..1).unwrap(), "f");
+        assert_eq!(foo.get(1..3).unwrap(), "ö");
+        assert_eq!(foo.get(1..=2).unwrap(), "ö");
+        assert_eq!(foo.get(3..).unwrap(), "💡");
+
+        // Empty.
+        assert!(foo.get(0..0).is_none());
+        assert!(foo.get(7..).is_none());
+
+        // Out of bounds.
+        assert!(foo.get(..8).is_none());
+
+        // Not on a char boundary.
+        assert!(foo.get(2..).is_none());
+    }
+
+    #[test]
+    fn collapse_whitespace() {
+        let foo = NonEmptyStr::new(" \tfoo  bar\n\u{3000}baz\u{a0} ").unwrap();
+        assert_eq!(foo.collapse_whitespace().unwrap(), "foo bar baz");
+
+        let foo = NonEmptyStr::new("foo").unwrap();
+        assert_eq!(foo.collapse_whitespace().unwrap(), "foo");
+
+        let whitespace = NonEmptyStr::new(" \t\u{3000}\n ").unwrap();
+        assert!(whitespace.collapse_whitespace().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::redundant_slicing)]
+    fn index() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+
+        let full: &NonEmptyStr = &foo[..];
+        assert_eq!(full, "fö💡");
+
+        let rest: &str = &foo[1..];
+        assert_eq!(rest, "ö💡");
+        assert_eq!(&foo[..1], "f");
+        assert_eq!(&foo[7..], "");
+    }
+
+    #[test]
+    fn split_at() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+
+        let (first, second) = foo.split_at(NonZeroUsize::new(1).unwrap());
+        assert_eq!(first, "f");
+        assert_eq!(second, "ö💡");
+
+        let (first, second) = foo.split_at(NonZeroUsize::new(3).unwrap());
+        assert_eq!(first, "fö");
+        assert_eq!(second, "💡");
+
+        let (first, second) = foo.split_at(foo.len_nonzero());
+        assert_eq!(first, "fö💡");
+        assert_eq!(second, "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_panic() {
+        let foo = NonEmptyStr::new("fö💡").unwrap();
+        let _ = foo.split_at(NonZeroUsize::new(2).unwrap());
+    }
+
+    #[test]
+    fn trim() {
+        let foo = NonEmptyStr::new(" \tfoo \n").unwrap();
+
+        assert_eq!(foo.trim().unwrap(), "foo");
+        assert_eq!(foo.trim_start().unwrap(), "foo \n");
+        assert_eq!(foo.trim_end().unwrap(), " \tfoo");
+
+        let whitespace = NonEmptyStr::new(" \t\n ").unwrap();
+
+        assert!(whitespace.trim().is_none());
+        assert!(whitespace.trim_start().is_none());
+        assert!(whitespace.trim_end().is_none());
+    }
+
+    #[test]
+    fn split() {
+        let foo = NonEmptyStr::new("foo\n\nbar\r\n").unwrap();
+        assert_eq!(foo.lines().collect::<Vec<_>>(), ["foo", "", "bar"]);
+        assert_eq!(foo.lines_ne().collect::<Vec<_>>(), ["foo", "bar"]);
+
+        let foo = NonEmptyStr::new("\r\n\n foo\r\n\r\n").unwrap();
+        assert_eq!(foo.lines_ne().collect::<Vec<_>>(), [" foo"]);
+        assert_eq!(ne_str!("\n\n").lines_ne().count(), 0);
+
+        let foo = NonEmptyStr::new(",foo,,bar,").unwrap();
+        assert_eq!(
+            foo.split(',').collect::<Vec<_>>(),
+            ["", "foo", "", "bar", ""]
+        );
+        assert_eq!(foo.split_ne(',').collect::<Vec<_>>(), ["foo", "bar"]);
+        assert_eq!(foo.split(",,").collect::<Vec<_>>(), [",foo", "bar,"]);
+
+        let commas = NonEmptyStr::new(",,").unwrap();
+        assert_eq!(commas.split_ne(',').count(), 0);
+    }
+
+    #[test]
+    fn split_once() {
+        let foo = NonEmptyStr::new("foo=bar=baz").unwrap();
+
+        assert_eq!(foo.split_once('='), Some(("foo", "bar=baz")));
+        assert_eq!(foo.rsplit_once('='), Some(("foo=bar", "baz")));
+        assert_eq!(foo.split_once(':'), None);
+        assert_eq!(foo.rsplit_once(':'), None);
+        assert_eq!(foo.split_once("=b"), Some(("foo", "ar=baz")));
+
+        assert_eq!(
+            foo.split_ne_once('='),
+            Some((Some(ne_str!("foo")), Some(ne_str!("bar=baz"))))
+        );
+        assert_eq!(
+            foo.rsplit_ne_once('='),
+            Some((Some(ne_str!("foo=bar")), Some(ne_str!("baz"))))
+        );
+        assert_eq!(foo.split_ne_once(':'), None);
+
+        let foo = NonEmptyStr::new("=foo=").unwrap();
+        assert_eq!(foo.split_once('='), Some(("", "foo=")));
+        assert_eq!(foo.split_ne_once('='), Some((None, Some(ne_str!("foo=")))));
+        assert_eq!(foo.rsplit_ne_once('='), Some((Some(ne_str!("=foo")), None)));
+
+        let eq = NonEmptyStr::new("=").unwrap();
+        assert_eq!(eq.split_ne_once('='), Some((None, None)));
+    }
+
+    #[test]
+    fn split_whitespace() {
+        let foo = NonEmptyStr::new("  foo\t\u{3000}bar \n\n baz ").unwrap();
+
+        let tokens = foo.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(tokens, ["foo", "bar", "baz"]);
+        assert!(tokens.iter().all(|t| !t.as_str().is_empty()));
+
+        // U+3000 is not ASCII whitespace.
+        let tokens = foo.split_ascii_whitespace().collect::<Vec<_>>();
+        assert_eq!(tokens, ["foo", "\u{3000}bar", "baz"]);
+        assert!(tokens.iter().all(|t| !t.as_str().is_empty()));
+
+        let whitespace = NonEmptyStr::new(" \t\n ").unwrap();
+        assert_eq!(whitespace.split_whitespace().count(), 0);
+        assert_eq!(whitespace.split_ascii_whitespace().count(), 0);
+    }
+
+    #[test]
+    fn find() {
+        let foo = NonEmptyStr::new("foöbarfoö").unwrap();
+
+        // The `str` search methods are provided by `Deref<Target = str>`.
+
+        assert_eq!(foo.find("foö"), Some(0));
+        assert_eq!(foo.rfind("foö"), Some(7));
+        assert_eq!(foo.find("bar"), Some(4));
+        assert_eq!(foo.find("baz"), None);
+        assert_eq!(foo.rfind("baz"), None);
+
+        assert!(foo.contains("ba"));
+        assert!(!foo.contains("baz"));
+        assert!(foo.starts_with("fo"));
+        assert!(!foo.starts_with("bar"));
+        assert!(foo.ends_with("oö"));
+        assert!(!foo.ends_with("bar"));
+
+        assert_eq!(foo.matches("foö").collect::<Vec<_>>(), ["foö", "foö"]);
+        assert_eq!(foo.matches("baz").count(), 0);
+
+        // Including the non-`&str` patterns.
+        assert_eq!(foo.find('b'), Some(4));
+        assert!(foo.starts_with('f'));
+        assert!(!foo.contains(char::is_whitespace));
+
+        assert_eq!(ne_str!("bar").find_in(foo), Some(4));
+        assert_eq!(ne_str!("foö").find_in("xfoö"), Some(1));
+        assert_eq!(ne_str!("baz").find_in(foo), None);
+        assert_eq!(foo.find_in("foö"), None);
+    }
+
+    #[test]
+    fn strip() {
+        let foobar = NonEmptyStr::new("foobar").unwrap();
+
+        assert_eq!(foobar.strip_prefix("foo"), Some("bar"));
+        assert_eq!(foobar.strip_prefix("foobar"), Some(""));
+        assert_eq!(foobar.strip_prefix("bar"), None);
+
+        assert_eq!(foobar.strip_suffix("bar"), Some("foo"));
+        assert_eq!(foobar.strip_suffix("foobar"), Some(""));
+        assert_eq!(foobar.strip_suffix("foo"), None);
+
+        // Non-`&str` patterns work via deref.
+        assert_eq!(foobar.strip_prefix('f'), Some("oobar"));
+
+        assert_eq!(foobar.strip_ne_prefix(ne_str!("foo")), Some("bar"));
+        assert_eq!(foobar.strip_ne_prefix(foobar), Some(""));
+        assert_eq!(foobar.strip_ne_prefix(ne_str!("bar")), None);
+
+        assert_eq!(foobar.strip_ne_suffix(ne_str!("bar")), Some("foo"));
+        assert_eq!(foobar.strip_ne_suffix(foobar), Some(""));
+        assert_eq!(foobar.strip_ne_suffix(ne_str!("foo")), None);
+
+        let strip_ne_prefix = |prefix| foobar.strip_ne_prefix(prefix).map(NonEmptyStr::new);
+        assert_eq!(strip_ne_prefix(ne_str!("foo")), Some(Some(ne_str!("bar"))));
+        assert_eq!(strip_ne_prefix(foobar), Some(None));
+        assert_eq!(strip_ne_prefix(ne_str!("bar")), None);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(ne_str!("42").parse::<i64>(), Ok(42));
+        assert_eq!(ne_str!("-7").parse::<i32>().unwrap(), -7);
+        assert!(ne_str!("foo").parse::<u8>().is_err());
+        assert!(ne_str!("true").parse::<bool>().unwrap());
+    }
+
+    #[test]
+    fn ascii() {
+        assert!(ne_str!("foo_42 !").is_ascii());
+        assert!(!ne_str!("foö").is_ascii());
+
+        assert!(ne_str!("Foo42").is_ascii_alphanumeric_all());
+        assert!(!ne_str!("foo_42").is_ascii_alphanumeric_all());
+        assert!(!ne_str!("foö").is_ascii_alphanumeric_all());
+    }
+
+    #[test]
+    fn case_conversion() {
+        let foo = NonEmptyStr::new("FoÖ bar").unwrap();
+
+        assert_eq!(foo.to_uppercase(), "FOÖ BAR");
+        assert_eq!(foo.to_lowercase(), "foö bar");
+        assert_eq!(foo.to_ascii_uppercase(), "FOÖ BAR");
+        assert_eq!(foo.to_ascii_lowercase(), "foÖ bar");
+
+        let foo_str = foo.to_owned();
+
+        assert_eq!(foo_str.to_uppercase(), "FOÖ BAR");
+        assert_eq!(foo_str.to_lowercase(), "foö bar");
+
+        assert!(foo.eq_ignore_ascii_case("fOÖ BAR"));
+        assert!(!foo.eq_ignore_ascii_case("foö bar"));
+        assert!(!foo.eq_ignore_ascii_case("foo"));
+        assert!(foo.eq_ignore_ascii_case(NonEmptyStr::new("FOÖ bAr").unwrap()));
+        assert!(foo.eq_ignore_ascii_case(&foo_str.to_ascii_uppercase()));
+    }
+
+    #[test]
+    fn capitalize_first() {
+        assert_eq!(ne_str!("foo bar").capitalize_first(), "Foo bar");
+        assert_eq!(ne_str!("Foo").capitalize_first(), "Foo");
+        assert_eq!(ne_str!("f").capitalize_first(), "F");
+        assert_eq!(ne_str!("öbar").capitalize_first(), "Öbar");
+        assert_eq!(ne_str!("ßa").capitalize_first(), "SSa");
+        assert_eq!(ne_str!("💡x").capitalize_first(), "💡x");
+    }
+
+    #[test]
+    fn concat() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+        let bar = NonEmptyStr::new("bar").unwrap();
+
+        let foobar = foo.concat(bar);
+        assert_eq!(foobar, "foobar");
+        assert!(foobar.inner().capacity() >= 6);
+
+        assert_eq!(foo + bar, "foobar");
+        assert_eq!(bar + foo, "barfoo");
+    }
+
+    #[test]
+    fn replace() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+        let bar = NonEmptyStr::new("bar").unwrap();
+
+        assert_eq!(foo.replace("o", "0").unwrap(), "f00");
+        assert_eq!(foo.replace("o", "").unwrap(), "f");
+        assert_eq!(foo.replace("x", "").unwrap(), "foo");
+        assert!(foo.replace("foo", "").is_none());
+
+        assert_eq!(foo.replace_ne("o", bar), "fbarbar");
+        assert_eq!(foo.replace_ne("foo", bar), "bar");
+        assert_eq!(foo.replace_ne("", bar), "barfbarobarobar");
+    }
+
+    #[test]
+    fn repeat() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+
+        assert_eq!(foo.repeat(NonZeroUsize::new(1).unwrap()), "foo");
+        assert_eq!(foo.repeat(NonZeroUsize::new(3).unwrap()), "foofoofoo");
+
+        let mut out = NonEmptyString::new("barbarbarbar".to_owned()).unwrap();
+        let capacity = out.capacity();
+
+        foo.repeat_into(NonZeroUsize::new(3).unwrap(), &mut out);
+        assert_eq!(out, "foofoofoo");
+        assert_eq!(out.capacity(), capacity);
+
+        foo.repeat_into(NonZeroUsize::new(1).unwrap(), &mut out);
+        assert_eq!(out, "foo");
+    }
+
+    #[test]
+    fn repeat_into_overflow() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut out = NonEmptyString::new("bar".to_owned()).unwrap();
+
+        // The length overflows `usize`.
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            ne_str!("foo").repeat_into(NonZeroUsize::new(usize::MAX).unwrap(), &mut out)
+        }))
+        .is_err());
+        assert_eq!(out, "bar");
+
+        // The length fits in `usize`, but the capacity overflows.
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            ne_str!("f").repeat_into(NonZeroUsize::new(usize::MAX).unwrap(), &mut out)
+        }))
+        .is_err());
+        assert_eq!(out, "bar");
+        assert_eq!(out.first_char(), 'b');
+    }
+
+    #[test]
+    fn hash_methods() {
+        let foo = NonEmptyStr::new("foo").unwrap();
+
+        assert_eq!(foo.hash_fnv1a(), str_hash_fnv1a("foo"));
+        assert_eq!(foo.hash_fnv1a_64(), str_hash_fnv1a_64("foo"));
+        #[cfg(feature = "std")]
+        assert_eq!(foo.hash_default(), str_hash_default("foo"));
+
+        const FOO_HASH: u32 = ne_str!("foo").hash_fnv1a();
+        assert_eq!(FOO_HASH, str_hash_fnv1a("foo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "tried to create a non-empty string slice from an empty source")]
+    fn new_const_panic() {
+        let empty = String::new();
+        let _ = NonEmptyStr::new_const(&empty);
+    }
+
     #[cfg(debug_assertions)]
     #[test]
     #[should_panic(expected = "tried to create a non-empty string slice from an empty source")]
@@ -1,20 +1,34 @@
 use {
     crate::*,
-    miniunchecked::*,
-    std::{
-        borrow::{Borrow, Cow},
-        cmp::PartialEq,
-        fmt::{Display, Formatter},
+    alloc::{
+        borrow::{Borrow, Cow, ToOwned},
+        boxed::Box,
+        rc::Rc,
+        string::String,
+        sync::Arc,
+        vec::Vec,
+    },
+    core::{
+        cmp::{Ordering, PartialEq},
+        fmt::{Display, Formatter, Write},
         num::NonZeroUsize,
-        ops::Deref,
+        ops::{Add, Deref, Index, RangeBounds},
+        str::{Bytes, CharIndices, FromStr},
     },
+    miniunchecked::*,
 };
 
 /// A non-empty [`String`].
 ///
 /// This is the owned version, [`NonEmptyStr`] is the borrowed version.
+///
+/// Hashes and compares exactly like the wrapped [`String`] / [`str`],
+/// so it may be looked up in hash maps and sets by a `&str` key.
+///
+/// Derefs to [`NonEmptyStr`] (and in turn to [`str`]), so the [`str`] search methods,
+/// e.g. [`str::find`], are available with their generic pattern arguments.
 #[repr(transparent)]
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NonEmptyString(String);
 
 impl NonEmptyString {
@@ -45,6 +59,98 @@ impl NonEmptyString {
         Self(s)
     }
 
+    /// Tries to create a [`NonEmptyString`] from the byte vector `bytes`.
+    /// Returns an error if `bytes` are empty or are not valid UTF-8.
+    pub fn from_utf8(bytes: Vec<u8>) -> Result<Self, NonEmptyFromUtf8Error> {
+        if bytes.is_empty() {
+            Err(NonEmptyFromUtf8Error::Empty)
+        } else {
+            String::from_utf8(bytes)
+                .map(|s| unsafe { Self::new_unchecked(s) })
+                .map_err(NonEmptyFromUtf8Error::InvalidUtf8)
+        }
+    }
+
+    /// Reads all bytes until EOF from the reader `r` into a new [`NonEmptyString`].
+    ///
+    /// Returns an error with [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) kind if nothing was read,
+    /// or with [`InvalidData`](std::io::ErrorKind::InvalidData) kind if the read bytes are not valid UTF-8.
+    /// In both cases the error wraps the [`NonEmptyFromUtf8Error`].
+    ///
+    /// See [`Read::read_to_end`](std::io::Read::read_to_end).
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(r: &mut R) -> std::io::Result<Self> {
+        use std::io::{Error, ErrorKind};
+
+        let mut bytes = Vec::new();
+        r.read_to_end(&mut bytes)?;
+
+        Self::from_utf8(bytes).map_err(|err| {
+            let kind = match err {
+                NonEmptyFromUtf8Error::Empty => ErrorKind::UnexpectedEof,
+                NonEmptyFromUtf8Error::InvalidUtf8(_) => ErrorKind::InvalidData,
+            };
+            Error::new(kind, err)
+        })
+    }
+
+    /// Creates a [`NonEmptyString`] from the byte slice `bytes`, replacing invalid UTF-8 sequences
+    /// with the [`replacement character`](char::REPLACEMENT_CHARACTER).
+    /// Returns `None` if `bytes` are empty.
+    ///
+    /// Non-empty `bytes` always result in a non-empty string,
+    /// as even invalid bytes are converted to (at least one) replacement character.
+    ///
+    /// See [`String::from_utf8_lossy`].
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            None
+        } else {
+            Some(unsafe { Self::new_unchecked(String::from_utf8_lossy(bytes).into_owned()) })
+        }
+    }
+
+    /// Tries to create a [`NonEmptyString`] from the chars in the `iter`.
+    /// Returns `None` if the `iter` is empty.
+    pub fn from_chars<I: IntoIterator<Item = char>>(iter: I) -> Option<Self> {
+        Self::new(iter.into_iter().collect())
+    }
+
+    /// Concatenates the [`non-empty string slices`](NonEmptyStr) from the `iter`
+    /// into a new [`NonEmptyString`].
+    /// Returns `None` if the `iter` is empty.
+    ///
+    /// The string slices are collected first to allocate the exact required capacity up front.
+    ///
+    /// See also [`join_ne`].
+    pub fn concat_all<'a, I: IntoIterator<Item = &'a NonEmptyStr>>(iter: I) -> Option<Self> {
+        let strs: Vec<&NonEmptyStr> = iter.into_iter().collect();
+
+        if strs.is_empty() {
+            return None;
+        }
+
+        let len = strs.iter().map(|s| s.len_nonzero().get()).sum();
+
+        let mut result = String::with_capacity(len);
+
+        for s in strs {
+            result.push_str(s);
+        }
+
+        Some(Self(result))
+    }
+
+    /// Creates a [`NonEmptyString`] with at least the specified capacity in bytes,
+    /// containing the char `first`.
+    ///
+    /// See [`String::with_capacity`].
+    pub fn with_capacity(cap: usize, first: char) -> Self {
+        let mut s = String::with_capacity(cap.max(first.len_utf8()));
+        s.push(first);
+        Self(s)
+    }
+
     /// Creates a [`NonEmptyString`] from the [`non-empty string slice`](NonEmptyStr) `s`.
     pub fn from(s: &NonEmptyStr) -> Self {
         unsafe { NonEmptyString::new_unchecked(s.as_str().to_owned()) }
@@ -79,12 +185,303 @@ impl NonEmptyString {
         self.0
     }
 
+    /// Converts this [`NonEmptyString`] into its UTF-8 bytes.
+    /// The returned byte vector is never empty.
+    ///
+    /// See [`String::into_bytes`].
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0.into_bytes()
+    }
+
+    /// Transforms this [`NonEmptyString`] by applying `f` to the inner string,
+    /// then checks that the result is still non-empty.
+    ///
+    /// Returns the empty string returned by `f` as an error if the result is empty.
+    pub fn try_map<F: FnOnce(String) -> String>(self, f: F) -> Result<NonEmptyString, String> {
+        let s = f(self.0);
+
+        if s.is_empty() {
+            Err(s)
+        } else {
+            Ok(unsafe { Self::new_unchecked(s) })
+        }
+    }
+
+    /// Returns a mutable reference to the inner [`String`].
+    ///
+    /// # Safety
+    /// The caller guarantees the string is not empty when the returned reference is dropped.
+    pub(crate) unsafe fn as_mut_string(&mut self) -> &mut String {
+        &mut self.0
+    }
+
+    /// Converts this [`NonEmptyString`] into a boxed [`NonEmptyStr`],
+    /// dropping any excess capacity.
+    pub fn into_boxed_ne_str(self) -> Box<NonEmptyStr> {
+        unsafe { Box::from_raw(Box::into_raw(self.0.into_boxed_str()) as *mut NonEmptyStr) }
+    }
+
+    /// Consumes and leaks this [`NonEmptyString`], returning a `&'static` [`NonEmptyStr`].
+    ///
+    /// See [`String::leak`].
+    pub fn leak(self) -> &'static NonEmptyStr {
+        unsafe { NonEmptyStr::new_unchecked(self.0.leak()) }
+    }
+
+    /// Returns an iterator over the bytes of this [`NonEmptyString`].
+    /// The iterator yields at least one byte.
+    ///
+    /// See [`NonEmptyStr::bytes`].
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.as_ne_str().bytes()
+    }
+
+    /// Returns an iterator over the chars of this [`NonEmptyString`] and their byte positions.
+    /// The iterator yields at least one char.
+    ///
+    /// See [`NonEmptyStr::char_indices`].
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        self.as_ne_str().char_indices()
+    }
+
     pub fn len_nonzero(&self) -> NonZeroUsize {
         unsafe {
             NonZeroUsize::new(self.0.len())
                 .unwrap_unchecked_dbg_msg("non-empty strings have non-zero length")
         }
     }
+
+    /// Returns the length of this [`NonEmptyString`] in bytes.
+    /// Never returns `0`; see also [`len_nonzero`](NonEmptyString::len_nonzero).
+    ///
+    /// See [`str::len`].
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the byte `index` lies on a char boundary of this [`NonEmptyString`]
+    /// (or is equal to its length).
+    ///
+    /// See [`str::is_char_boundary`].
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        self.0.is_char_boundary(index)
+    }
+
+    /// Returns this [`NonEmptyString`]'s capacity in bytes.
+    ///
+    /// See [`String::capacity`].
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be appended to this [`NonEmptyString`].
+    ///
+    /// See [`String::reserve`].
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more bytes to be appended to this [`NonEmptyString`].
+    ///
+    /// See [`String::reserve_exact`].
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Shrinks the capacity of this [`NonEmptyString`] to match its length.
+    ///
+    /// See [`String::shrink_to_fit`].
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity of this [`NonEmptyString`] with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the `min_capacity`.
+    ///
+    /// See [`String::shrink_to`].
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.0.shrink_to(min_capacity)
+    }
+
+    /// Appends the string slice `s` to the end of this [`NonEmptyString`].
+    pub fn push_str(&mut self, s: &str) {
+        self.0.push_str(s)
+    }
+
+    /// Appends the non-empty string slice `s` to the end of this [`NonEmptyString`].
+    ///
+    /// Same as [`push_str`](NonEmptyString::push_str), but guarantees the string grows by at least one char.
+    pub fn push_ne_str(&mut self, s: &NonEmptyStr) {
+        self.push_str(s.as_str())
+    }
+
+    /// Appends the char `c` to the end of this [`NonEmptyString`].
+    pub fn push(&mut self, c: char) {
+        self.0.push(c)
+    }
+
+    /// Inserts the char `c` into this [`NonEmptyString`] at the byte position `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the string's length, or if it does not lie on a char boundary.
+    ///
+    /// See [`String::insert`].
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.0.insert(idx, c)
+    }
+
+    /// Inserts the string slice `s` into this [`NonEmptyString`] at the byte position `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the string's length, or if it does not lie on a char boundary.
+    ///
+    /// See [`String::insert_str`].
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.0.insert_str(idx, s)
+    }
+
+    /// Shortens this [`NonEmptyString`] to `new_len` bytes.
+    /// Has no effect if `new_len` is greater than or equal to the string's length.
+    ///
+    /// # Panics
+    /// Panics if `new_len` does not lie on a char boundary.
+    ///
+    /// See [`String::truncate`].
+    pub fn truncate(&mut self, new_len: NonZeroUsize) {
+        self.0.truncate(new_len.get())
+    }
+
+    /// Converts this [`NonEmptyString`] to its ASCII upper case equivalent in-place.
+    ///
+    /// See [`String::make_ascii_uppercase`](str::make_ascii_uppercase).
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase()
+    }
+
+    /// Converts this [`NonEmptyString`] to its ASCII lower case equivalent in-place.
+    ///
+    /// See [`String::make_ascii_lowercase`](str::make_ascii_lowercase).
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase()
+    }
+
+    /// Retains only the chars of this [`NonEmptyString`] for which the predicate `f` returns `true`.
+    ///
+    /// Returns an error and leaves the string unchanged if no chars would be retained,
+    /// as that would leave the string empty.
+    ///
+    /// See [`String::retain`].
+    #[allow(clippy::result_unit_err)]
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) -> Result<(), ()> {
+        // Find the first retained char before modifying the string, so it is left unchanged on error.
+        let (first, _) = self.0.char_indices().find(|&(_, c)| f(c)).ok_or(())?;
+
+        // Drop the rejected leading chars, keep the first retained one,
+        // and only call `f` for the chars after it, i.e. once per char, like `String::retain`.
+        self.0.drain(..first);
+        let mut is_first = true;
+        self.0
+            .retain(|c| core::mem::replace(&mut is_first, false) || f(c));
+
+        Ok(())
+    }
+
+    /// Replaces the specified `range` of this [`NonEmptyString`] with the string slice `replace_with`.
+    ///
+    /// Returns an error and leaves the string unchanged if the entire string would be replaced with an empty string,
+    /// as that would leave the string empty.
+    ///
+    /// # Panics
+    /// Panics if the `range` is out of bounds or does not lie on char boundaries.
+    ///
+    /// See [`String::replace_range`].
+    #[allow(clippy::result_unit_err)]
+    pub fn replace_range<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        replace_with: &str,
+    ) -> Result<(), ()> {
+        // Panics on invalid ranges before anything is modified.
+        let replaced = self.0[(range.start_bound().cloned(), range.end_bound().cloned())].len();
+
+        if replaced == self.0.len() && replace_with.is_empty() {
+            Err(())
+        } else {
+            self.0.replace_range(range, replace_with);
+            Ok(())
+        }
+    }
+
+    /// Removes the last char from this [`NonEmptyString`] and returns it.
+    ///
+    /// Returns `None` and leaves the string unchanged if it only contains a single char,
+    /// as removing it would leave the string empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let mut chars = self.0.chars();
+        chars.next_back();
+
+        if chars.as_str().is_empty() {
+            None
+        } else {
+            self.0.pop()
+        }
+    }
+
+    /// Removes the last char from this [`NonEmptyString`] and returns it.
+    ///
+    /// Returns an error and leaves the string unchanged if it only contains a single char,
+    /// as removing it would leave the string empty.
+    #[allow(clippy::result_unit_err)]
+    pub fn try_pop(&mut self) -> Result<char, ()> {
+        self.pop().ok_or(())
+    }
+}
+
+/// Joins the [`non-empty string slices`](NonEmptyStr) from the `iter`, separated by `sep`,
+/// into a new [`NonEmptyString`].
+///
+/// Returns `None` if the `iter` is empty.
+/// Otherwise the result is never empty, even if `sep` is.
+pub fn join_ne<'a, I: IntoIterator<Item = &'a NonEmptyStr>>(
+    iter: I,
+    sep: &str,
+) -> Option<NonEmptyString> {
+    let mut iter = iter.into_iter();
+    let mut result = String::from(iter.next()?.as_str());
+
+    for s in iter {
+        result.push_str(sep);
+        result.push_str(s);
+    }
+
+    Some(unsafe { NonEmptyString::new_unchecked(result) })
+}
+
+/// Indexes the [`NonEmptyStr`].
+/// A full range slice is a [`NonEmptyStr`], other ranges may produce an empty [`str`].
+impl<I> Index<I> for NonEmptyString
+where
+    NonEmptyStr: Index<I>,
+{
+    type Output = <NonEmptyStr as Index<I>>::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ne_str()[index]
+    }
+}
+
+/// Implemented manually (instead of derived) to forward [`clone_from`](Clone::clone_from)
+/// to the inner string, reusing its allocation.
+impl Clone for NonEmptyString {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0)
+    }
 }
 
 impl Deref for NonEmptyString {
@@ -101,6 +498,12 @@ impl AsRef<NonEmptyStr> for NonEmptyString {
     }
 }
 
+impl AsRef<[u8]> for NonEmptyString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl AsRef<String> for NonEmptyString {
     fn as_ref(&self) -> &String {
         self.inner()
@@ -113,32 +516,65 @@ impl AsRef<str> for NonEmptyString {
     }
 }
 
+impl Add<&NonEmptyStr> for NonEmptyString {
+    type Output = NonEmptyString;
+
+    fn add(mut self, rhs: &NonEmptyStr) -> Self::Output {
+        self.push_str(rhs);
+        self
+    }
+}
+
+impl Extend<char> for NonEmptyString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a str> for NonEmptyString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a NonEmptyStr> for NonEmptyString {
+    fn extend<I: IntoIterator<Item = &'a NonEmptyStr>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(NonEmptyStr::as_str))
+    }
+}
+
 impl Borrow<NonEmptyStr> for NonEmptyString {
     fn borrow(&self) -> &NonEmptyStr {
         self.as_ne_str()
     }
 }
 
+impl Borrow<str> for NonEmptyString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
 // Fallible conversions from string slices and owned strings.
 ////////////////////////////////////////////////////////////
 impl<'s> TryFrom<&'s str> for NonEmptyString {
-    type Error = ();
+    type Error = EmptyStringError;
 
     fn try_from(s: &'s str) -> Result<Self, Self::Error> {
-        Self::new(s.to_owned()).ok_or(())
+        Self::new(s.to_owned()).ok_or(EmptyStringError)
     }
 }
 
 impl TryFrom<String> for NonEmptyString {
-    type Error = ();
+    type Error = EmptyStringError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
-        Self::new(s).ok_or(())
+        Self::new(s).ok_or(EmptyStringError)
     }
 }
 
 impl<'s> TryFrom<Cow<'s, str>> for NonEmptyString {
-    type Error = ();
+    type Error = EmptyStringError;
 
     fn try_from(s: Cow<'s, str>) -> Result<Self, Self::Error> {
         match s {
@@ -147,15 +583,50 @@ impl<'s> TryFrom<Cow<'s, str>> for NonEmptyString {
         }
     }
 }
+
+/// See [`NonEmptyString::from_utf8`].
+/// The original bytes may be recovered from the [`NonEmptyFromUtf8Error::InvalidUtf8`] error.
+impl TryFrom<Vec<u8>> for NonEmptyString {
+    type Error = NonEmptyFromUtf8Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        NonEmptyString::from_utf8(bytes)
+    }
+}
+
+impl FromStr for NonEmptyString {
+    type Err = EmptyStringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(EmptyStringError)
+        } else {
+            Ok(unsafe { Self::from_unchecked(s) })
+        }
+    }
+}
 ////////////////////////////////////////////////////////////
 
-// Infallible conversion from a non-empty string slice.
+// Infallible conversions from a non-empty string slice, a boxed non-empty string slice and a char.
 ////////////////////////////////////////////////////////////
 impl<'s> From<&'s NonEmptyStr> for NonEmptyString {
     fn from(s: &'s NonEmptyStr) -> Self {
         Self::from(s)
     }
 }
+
+impl From<char> for NonEmptyString {
+    fn from(c: char) -> Self {
+        unsafe { Self::new_unchecked(c.into()) }
+    }
+}
+
+impl From<Box<NonEmptyStr>> for NonEmptyString {
+    fn from(s: Box<NonEmptyStr>) -> Self {
+        let s = unsafe { Box::from_raw(Box::into_raw(s) as *mut str) };
+        unsafe { Self::new_unchecked(s.into_string()) }
+    }
+}
 ////////////////////////////////////////////////////////////
 
 // Infallible conversions into string slices and owned strings.
@@ -189,6 +660,24 @@ impl<'s> From<&'s NonEmptyString> for Cow<'s, NonEmptyStr> {
         Cow::Borrowed(val.as_ne_str())
     }
 }
+
+impl From<NonEmptyString> for Box<NonEmptyStr> {
+    fn from(val: NonEmptyString) -> Self {
+        val.into_boxed_ne_str()
+    }
+}
+
+impl From<NonEmptyString> for Arc<NonEmptyStr> {
+    fn from(val: NonEmptyString) -> Self {
+        val.as_ne_str().into()
+    }
+}
+
+impl From<NonEmptyString> for Rc<NonEmptyStr> {
+    fn from(val: NonEmptyString) -> Self {
+        val.as_ne_str().into()
+    }
+}
 ////////////////////////////////////////////////////////////
 
 // Comparsions.
@@ -385,12 +874,178 @@ impl PartialEq<NonEmptyStr> for &NonEmptyString {
 }
 ////////////////////////////////////////////////////////////
 
+// <Cow<NonEmptyStr>>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<Cow<'_, NonEmptyStr>> for NonEmptyString {
+    fn eq(&self, other: &Cow<'_, NonEmptyStr>) -> bool {
+        PartialEq::eq(self.as_ne_str(), other.as_ref())
+    }
+}
+
+impl PartialEq<Cow<'_, NonEmptyStr>> for &NonEmptyString {
+    fn eq(&self, other: &Cow<'_, NonEmptyStr>) -> bool {
+        PartialEq::eq(self.as_ne_str(), other.as_ref())
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyString> for Cow<'_, NonEmptyStr> {
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        PartialEq::eq(self.as_ref(), other.as_ne_str())
+    }
+}
+
+impl PartialEq<&NonEmptyString> for Cow<'_, NonEmptyStr> {
+    fn eq(&self, other: &&NonEmptyString) -> bool {
+        PartialEq::eq(self.as_ref(), other.as_ne_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <char>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialEq<char> for NonEmptyString {
+    fn eq(&self, other: &char) -> bool {
+        self.as_ne_str().eq_char(*other)
+    }
+}
+
+impl PartialEq<char> for &NonEmptyString {
+    fn eq(&self, other: &char) -> bool {
+        self.as_ne_str().eq_char(*other)
+    }
+}
+
+// Reverse
+
+impl PartialEq<NonEmptyString> for char {
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        other.as_ne_str().eq_char(*self)
+    }
+}
+
+impl PartialEq<&NonEmptyString> for char {
+    fn eq(&self, other: &&NonEmptyString) -> bool {
+        other.as_ne_str().eq_char(*self)
+    }
+}
+////////////////////////////////////////////////////////////
+
+// Ordering comparisons.
+
+// <str>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialOrd<str> for NonEmptyString {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other)
+    }
+}
+
+impl PartialOrd<&str> for NonEmptyString {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), *other)
+    }
+}
+
+impl PartialOrd<str> for &NonEmptyString {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other)
+    }
+}
+
+// Reverse
+
+impl PartialOrd<NonEmptyString> for str {
+    fn partial_cmp(&self, other: &NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self, other.as_str())
+    }
+}
+
+impl PartialOrd<&NonEmptyString> for str {
+    fn partial_cmp(&self, other: &&NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self, other.as_str())
+    }
+}
+
+impl PartialOrd<NonEmptyString> for &str {
+    fn partial_cmp(&self, other: &NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(*self, other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
+// <String>
+////////////////////////////////////////////////////////////
+
+// Direct
+
+impl PartialOrd<String> for NonEmptyString {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<&String> for NonEmptyString {
+    fn partial_cmp(&self, other: &&String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<String> for &NonEmptyString {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+// Reverse
+
+impl PartialOrd<NonEmptyString> for String {
+    fn partial_cmp(&self, other: &NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<&NonEmptyString> for String {
+    fn partial_cmp(&self, other: &&NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl PartialOrd<NonEmptyString> for &String {
+    fn partial_cmp(&self, other: &NonEmptyString) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+////////////////////////////////////////////////////////////
+
 impl Display for NonEmptyString {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.inner().fmt(f)
     }
 }
 
+impl Write for NonEmptyString {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -463,10 +1118,10 @@ mod tests {
             use std::convert::TryInto;
 
             let ne_foo_str: Result<NonEmptyString, _> = "".try_into();
-            assert!(ne_foo_str.is_err());
+            assert_eq!(ne_foo_str, Err(EmptyStringError));
 
             let ne_foo_str = <NonEmptyString as TryFrom<&str>>::try_from("");
-            assert!(ne_foo_str.is_err());
+            assert_eq!(ne_foo_str, Err(EmptyStringError));
         }
 
         // try from non-empty `String`
@@ -488,13 +1143,636 @@ mod tests {
             let empty_str = "".to_owned();
 
             let ne_foo_str: Result<NonEmptyString, _> = empty_str.clone().try_into();
-            assert!(ne_foo_str.is_err());
+            assert_eq!(ne_foo_str, Err(EmptyStringError));
 
             let ne_foo_str = <NonEmptyString as TryFrom<String>>::try_from(empty_str);
-            assert!(ne_foo_str.is_err());
+            assert_eq!(ne_foo_str, Err(EmptyStringError));
         }
     }
 
+    #[test]
+    fn from_str() {
+        let ne_foo_str: NonEmptyString = "foo".parse().unwrap();
+        cmp(&ne_foo_str, "foo");
+
+        let ne_foo_str = <NonEmptyString as FromStr>::from_str("foo").unwrap();
+        cmp(&ne_foo_str, "foo");
+
+        assert_eq!("".parse::<NonEmptyString>(), Err(EmptyStringError));
+        assert_eq!(EmptyStringError.to_string(), "string is empty");
+    }
+
+    #[test]
+    fn from_utf8() {
+        let ne_foo_str = NonEmptyString::from_utf8(b"foo".to_vec()).unwrap();
+        cmp(&ne_foo_str, "foo");
+
+        assert_eq!(
+            NonEmptyString::from_utf8(Vec::new()),
+            Err(NonEmptyFromUtf8Error::Empty)
+        );
+
+        match NonEmptyString::from_utf8(b"fo\xff".to_vec()) {
+            Err(NonEmptyFromUtf8Error::InvalidUtf8(err)) => {
+                assert_eq!(err.into_bytes(), b"fo\xff")
+            }
+            _ => panic!("expected an invalid UTF-8 error"),
+        }
+
+        let ne_foo_str: NonEmptyString = b"foo".to_vec().try_into().unwrap();
+        cmp(&ne_foo_str, "foo");
+
+        assert_eq!(
+            NonEmptyString::try_from(Vec::new()),
+            Err(NonEmptyFromUtf8Error::Empty)
+        );
+
+        match NonEmptyString::try_from(b"fo\xff".to_vec()) {
+            Err(NonEmptyFromUtf8Error::InvalidUtf8(err)) => {
+                assert_eq!(err.into_bytes(), b"fo\xff")
+            }
+            _ => panic!("expected an invalid UTF-8 error"),
+        }
+    }
+
+    #[test]
+    fn from_utf8_lossy() {
+        let ne_foo_str = NonEmptyString::from_utf8_lossy(b"foo").unwrap();
+        cmp(&ne_foo_str, "foo");
+
+        let ne_foo_str = NonEmptyString::from_utf8_lossy(b"fo\xff").unwrap();
+        cmp(&ne_foo_str, "fo\u{FFFD}");
+
+        // Only invalid bytes still result in a non-empty string.
+        let ne_foo_str = NonEmptyString::from_utf8_lossy(b"\xff").unwrap();
+        cmp(&ne_foo_str, "\u{FFFD}");
+
+        assert!(NonEmptyString::from_utf8_lossy(b"").is_none());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref, clippy::cmp_owned)]
+    fn comparison_matrix() {
+        let foo_string = NonEmptyString::new("foo".to_owned()).unwrap();
+        let foo_str: &NonEmptyStr = ne_str!("foo");
+        let foo_borrowed: Cow<'_, NonEmptyStr> = Cow::Borrowed(foo_str);
+        let foo_owned: Cow<'_, NonEmptyStr> = Cow::Owned(foo_string.clone());
+
+        let bar_string = NonEmptyString::new("bar".to_owned()).unwrap();
+        let bar_str: &NonEmptyStr = ne_str!("bar");
+        let bar_cow: Cow<'_, NonEmptyStr> = Cow::Borrowed(bar_str);
+
+        macro_rules! cmp_all {
+            ($lhs:expr, $foo:expr, $bar:expr) => {
+                assert!($lhs == $foo);
+                assert!($foo == $lhs);
+                assert!($lhs != $bar);
+                assert!($bar != $lhs);
+            };
+        }
+
+        for foo_cow in [&foo_borrowed, &foo_owned] {
+            // `NonEmptyString`
+            cmp_all!(foo_string, foo_string, bar_string);
+            cmp_all!(foo_string, &foo_string, &bar_string);
+            cmp_all!(foo_string, *foo_str, *bar_str);
+            cmp_all!(foo_string, foo_str, bar_str);
+            cmp_all!(foo_string, *foo_cow, bar_cow);
+
+            // `&NonEmptyString`
+            cmp_all!(&foo_string, &foo_string, &bar_string);
+            cmp_all!(&foo_string, *foo_str, *bar_str);
+            cmp_all!(&foo_string, foo_str, bar_str);
+            cmp_all!(&foo_string, *foo_cow, bar_cow);
+
+            // `NonEmptyStr`
+            cmp_all!(*foo_str, *foo_str, *bar_str);
+            cmp_all!(*foo_str, foo_str, bar_str);
+            cmp_all!(*foo_str, *foo_cow, bar_cow);
+
+            // `&NonEmptyStr`
+            cmp_all!(foo_str, foo_str, bar_str);
+            cmp_all!(foo_str, *foo_cow, bar_cow);
+
+            // `Cow<NonEmptyStr>`
+            cmp_all!(*foo_cow, *foo_cow, bar_cow);
+        }
+    }
+
+    #[test]
+    fn char_comparison() {
+        let plus_string = NonEmptyString::from(ne_str!("+"));
+        let plus_str = ne_str!("+");
+
+        macro_rules! cmp_char {
+            ($lhs:expr, $c:expr, $other:expr) => {
+                assert!($lhs == $c);
+                assert!($c == $lhs);
+                assert!($lhs != $other);
+                assert!($other != $lhs);
+            };
+        }
+
+        cmp_char!(plus_string, '+', '-');
+        cmp_char!(&plus_string, '+', '-');
+        cmp_char!(*plus_str, '+', '-');
+        cmp_char!(plus_str, '+', '-');
+
+        // Multi-byte chars.
+        cmp_char!(*ne_str!("💡"), '💡', 'ö');
+        cmp_char!(NonEmptyString::from(ne_str!("ö")), 'ö', 'o');
+
+        // More than one char.
+        assert!(ne_str!("++") != '+');
+        assert!('+' != NonEmptyString::from(ne_str!("+-")));
+        assert!(ne_str!("ö💡") != 'ö');
+    }
+
+    #[test]
+    #[allow(clippy::op_ref, clippy::cmp_owned)]
+    fn ordering() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+        let foo_str = "foo".to_owned();
+
+        assert!(foo < "zzz");
+        assert!(foo > *"aaa");
+        assert!(&foo <= *"foo");
+        assert!("zzz" > foo);
+        assert!(*"aaa" < foo);
+        assert!("foo" >= &foo);
+
+        assert!(foo < "zzz".to_owned());
+        assert!(foo > &"aaa".to_owned());
+        assert!(&foo <= foo_str);
+        assert!("zzz".to_owned() > foo);
+        assert!("aaa".to_owned() < &foo);
+        assert!(&foo_str >= foo);
+
+        assert_eq!(foo.partial_cmp("foo"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn hash() {
+        use std::{
+            collections::{hash_map::DefaultHasher, HashMap, HashSet},
+            hash::{Hash, Hasher},
+        };
+
+        fn hash<T: Hash + ?Sized>(val: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            val.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        assert_eq!(hash(&foo), hash("foo"));
+        assert_eq!(hash(foo.as_ne_str()), hash("foo"));
+
+        let mut map = HashMap::new();
+        map.insert(foo.clone(), 7);
+
+        assert_eq!(map.get("foo"), Some(&7));
+        assert_eq!(map.get(foo.as_ne_str()), Some(&7));
+        assert_eq!(map.get("bar"), None);
+
+        let mut set = HashSet::new();
+        set.insert(foo.as_ne_str());
+
+        assert!(set.contains("foo"));
+    }
+
+    #[test]
+    fn error() {
+        fn parse(s: &str) -> Result<NonEmptyString, Box<dyn std::error::Error>> {
+            Ok(s.try_into()?)
+        }
+
+        cmp(&parse("foo").unwrap(), "foo");
+        assert_eq!(parse("").unwrap_err().to_string(), "string is empty");
+    }
+
+    #[test]
+    fn from_chars() {
+        cmp(&NonEmptyString::from_chars("foo".chars()).unwrap(), "foo");
+        cmp(&NonEmptyString::from_chars(['💡']).unwrap(), "💡");
+
+        assert!(NonEmptyString::from_chars("".chars()).is_none());
+        assert!(NonEmptyString::from_chars(std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn from_char() {
+        let a: NonEmptyString = 'a'.into();
+        cmp(&a, "a");
+        assert_eq!(a.len_nonzero().get(), 1);
+
+        let bulb = <NonEmptyString as From<char>>::from('💡');
+        cmp(&bulb, "💡");
+        assert_eq!(bulb.len_nonzero().get(), 4);
+    }
+
+    #[test]
+    fn boxed() {
+        let ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let boxed = ne_foo_str.clone().into_boxed_ne_str();
+        assert_eq!(&*boxed, "foo");
+        assert_eq!(boxed.len_nonzero().get(), 3);
+
+        let boxed: Box<NonEmptyStr> = ne_foo_str.into();
+        assert_eq!(&*boxed, "foo");
+
+        let ne_foo_str: NonEmptyString = boxed.into();
+        cmp(&ne_foo_str, "foo");
+    }
+
+    #[test]
+    fn as_ref_bytes() {
+        fn bytes<B: AsRef<[u8]>>(b: &B) -> &[u8] {
+            b.as_ref()
+        }
+
+        let foo = NonEmptyString::new("fö".to_owned()).unwrap();
+        assert_eq!(bytes(&foo), "fö".as_bytes());
+        assert_eq!(bytes(&&foo), "fö".as_bytes());
+    }
+
+    #[test]
+    fn clone_from() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let mut bar = NonEmptyString::with_capacity(64, 'b');
+        bar.push_str("ar");
+
+        bar.clone_from(&foo);
+        cmp(&bar, "foo");
+
+        cmp(&foo.clone(), "foo");
+    }
+
+    #[test]
+    fn debug() {
+        let foo = NonEmptyString::new("f\"oo".to_owned()).unwrap();
+
+        assert_eq!(format!("{:?}", foo), r#"NonEmptyString("f\"oo")"#);
+        assert_eq!(format!("{}", foo), r#"f"oo"#);
+    }
+
+    #[test]
+    fn try_map() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let foobar = foo.try_map(|s| s + "bar").unwrap();
+        cmp(&foobar, "foobar");
+
+        let barbar = foobar.try_map(|s| s.replace("foo", "bar")).unwrap();
+        cmp(&barbar, "barbar");
+
+        assert_eq!(barbar.try_map(|s| s.replace("bar", "")), Err(String::new()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader() {
+        use std::io::ErrorKind;
+
+        let mut reader: &[u8] = "fö💡".as_bytes();
+        cmp(&NonEmptyString::from_reader(&mut reader).unwrap(), "fö💡");
+
+        let mut reader: &[u8] = b"";
+        assert_eq!(
+            NonEmptyString::from_reader(&mut reader).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let mut reader: &[u8] = b"\xff";
+        assert_eq!(
+            NonEmptyString::from_reader(&mut reader).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn into_bytes() {
+        let foo = NonEmptyString::new("fö".to_owned()).unwrap();
+        assert_eq!(foo.into_bytes(), "fö".as_bytes());
+    }
+
+    #[test]
+    fn leak() {
+        let ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let leaked: &'static NonEmptyStr = ne_foo_str.leak();
+        assert_eq!(leaked, "foo");
+    }
+
+    #[test]
+    fn shared() {
+        let ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let arc: Arc<NonEmptyStr> = ne_foo_str.clone().into();
+        assert_eq!(&*arc, "foo");
+
+        let rc: Rc<NonEmptyStr> = ne_foo_str.into();
+        assert_eq!(&*rc, "foo");
+    }
+
+    #[test]
+    fn push() {
+        let mut ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        ne_foo_str.push_str("");
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.push_str("bar");
+        cmp(&ne_foo_str, "foobar");
+
+        ne_foo_str.push('!');
+        cmp(&ne_foo_str, "foobar!");
+        assert_eq!(ne_foo_str.len_nonzero().get(), 7);
+
+        ne_foo_str.push_ne_str(ne_str!("bö"));
+        cmp(&ne_foo_str, "foobar!bö");
+    }
+
+    #[test]
+    fn write() {
+        let mut ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        let (num, c) = (7, 'ö');
+        write!(&mut ne_foo_str, "{}{}", num, c).unwrap();
+        cmp(&ne_foo_str, "foo7ö");
+
+        ne_foo_str.write_str("").unwrap();
+        cmp(&ne_foo_str, "foo7ö");
+    }
+
+    #[test]
+    fn extend() {
+        let mut ne_foo_str = NonEmptyString::new("f".to_owned()).unwrap();
+
+        ne_foo_str.extend(['o', 'o']);
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.extend(["", "bar"]);
+        cmp(&ne_foo_str, "foobar");
+
+        ne_foo_str.extend([ne_str!("baz"), ne_str!("!")]);
+        cmp(&ne_foo_str, "foobarbaz!");
+
+        ne_foo_str.extend(core::iter::empty::<char>());
+        cmp(&ne_foo_str, "foobarbaz!");
+    }
+
+    #[test]
+    fn add() {
+        let foo = NonEmptyString::new("foo".to_owned()).unwrap();
+        let bar = NonEmptyStr::new("bar").unwrap();
+
+        let foobar = foo + bar;
+        cmp(&foobar, "foobar");
+
+        let foobarbar = foobar.clone() + NonEmptyStr::new(&foobar[3..]).unwrap();
+        cmp(&foobarbar, "foobarbar");
+    }
+
+    #[test]
+    fn join() {
+        let components: Vec<&NonEmptyStr> = ["foo", "bar", "baz"]
+            .into_iter()
+            .map(|s| NonEmptyStr::new(s).unwrap())
+            .collect();
+
+        cmp(
+            &join_ne(components.iter().copied(), "/").unwrap(),
+            "foo/bar/baz",
+        );
+        cmp(
+            &join_ne(components.iter().copied(), "").unwrap(),
+            "foobarbaz",
+        );
+        cmp(
+            &join_ne(components[..1].iter().copied(), "/").unwrap(),
+            "foo",
+        );
+
+        assert!(join_ne(components[..0].iter().copied(), "/").is_none());
+
+        let foobarbaz = NonEmptyString::concat_all(components.iter().copied()).unwrap();
+        cmp(&foobarbaz, "foobarbaz");
+        assert!(foobarbaz.capacity() >= 9);
+
+        cmp(
+            &NonEmptyString::concat_all(components[..1].iter().copied()).unwrap(),
+            "foo",
+        );
+        assert!(NonEmptyString::concat_all(components[..0].iter().copied()).is_none());
+    }
+
+    #[test]
+    fn insert() {
+        let mut ne_foo_str = NonEmptyString::new("ö".to_owned()).unwrap();
+
+        ne_foo_str.insert(0, 'f');
+        cmp(&ne_foo_str, "fö");
+
+        ne_foo_str.insert(3, 'o');
+        cmp(&ne_foo_str, "föo");
+
+        ne_foo_str.insert_str(0, "");
+        cmp(&ne_foo_str, "föo");
+
+        ne_foo_str.insert_str(1, "oo");
+        cmp(&ne_foo_str, "fooöo");
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_panic() {
+        let mut ne_foo_str = NonEmptyString::new("ö".to_owned()).unwrap();
+        ne_foo_str.insert(1, 'f');
+    }
+
+    #[test]
+    fn truncate() {
+        let mut ne_foo_str = NonEmptyString::new("foöbar".to_owned()).unwrap();
+
+        ne_foo_str.truncate(NonZeroUsize::new(10).unwrap());
+        cmp(&ne_foo_str, "foöbar");
+
+        ne_foo_str.truncate(NonZeroUsize::new(4).unwrap());
+        cmp(&ne_foo_str, "foö");
+
+        ne_foo_str.truncate(NonZeroUsize::new(1).unwrap());
+        cmp(&ne_foo_str, "f");
+    }
+
+    #[test]
+    #[should_panic]
+    fn truncate_panic() {
+        let mut ne_foo_str = NonEmptyString::new("foö".to_owned()).unwrap();
+        ne_foo_str.truncate(NonZeroUsize::new(3).unwrap());
+    }
+
+    #[test]
+    fn pop() {
+        let mut ne_foo_str = NonEmptyString::new("fö💡".to_owned()).unwrap();
+
+        assert_eq!(ne_foo_str.pop(), Some('💡'));
+        cmp(&ne_foo_str, "fö");
+
+        assert_eq!(ne_foo_str.try_pop(), Ok('ö'));
+        cmp(&ne_foo_str, "f");
+
+        assert_eq!(ne_foo_str.pop(), None);
+        cmp(&ne_foo_str, "f");
+
+        assert_eq!(ne_foo_str.try_pop(), Err(()));
+        cmp(&ne_foo_str, "f");
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut foo = NonEmptyString::new("foo".to_owned()).unwrap();
+
+        foo.replace_range(1.., "ö💡").unwrap();
+        cmp(&foo, "fö💡");
+
+        foo.replace_range(..1, "").unwrap();
+        cmp(&foo, "ö💡");
+
+        foo.replace_range(.., "bar").unwrap();
+        cmp(&foo, "bar");
+
+        // Would be empty - the string is unchanged.
+        assert_eq!(foo.replace_range(.., ""), Err(()));
+        cmp(&foo, "bar");
+
+        assert_eq!(foo.replace_range(0..3, ""), Err(()));
+        cmp(&foo, "bar");
+    }
+
+    #[test]
+    #[should_panic]
+    fn replace_range_not_char_boundary() {
+        let mut foo = NonEmptyString::new("fö".to_owned()).unwrap();
+        let _ = foo.replace_range(..2, "");
+    }
+
+    #[test]
+    fn len() {
+        let ne_foo_str = NonEmptyString::new("fö💡".to_owned()).unwrap();
+        assert_eq!(ne_foo_str.len(), 7);
+        assert_eq!(ne_foo_str.len(), ne_foo_str.len_nonzero().get());
+
+        assert!(ne_foo_str.is_char_boundary(1));
+        assert!(!ne_foo_str.is_char_boundary(2));
+        assert!(ne_foo_str.is_char_boundary(7));
+    }
+
+    #[test]
+    fn forwarders() {
+        let ne_foo_str = NonEmptyString::new("fö💡".to_owned()).unwrap();
+
+        assert_eq!(ne_foo_str.bytes().collect::<Vec<_>>(), "fö💡".as_bytes());
+        assert_eq!(
+            ne_foo_str.char_indices().collect::<Vec<_>>(),
+            [(0, 'f'), (1, 'ö'), (3, '💡')]
+        );
+        assert_eq!(ne_foo_str.find("💡"), Some(3));
+        assert_eq!(ne_foo_str.find("bar"), None);
+        assert_eq!(ne_foo_str.find('💡'), Some(3));
+    }
+
+    #[test]
+    fn index() {
+        let ne_foo_str = NonEmptyString::new("fö💡".to_owned()).unwrap();
+
+        let full: &NonEmptyStr = &ne_foo_str[..];
+        assert_eq!(full, "fö💡");
+
+        let rest: &str = &ne_foo_str[1..];
+        assert_eq!(rest, "ö💡");
+        assert_eq!(&ne_foo_str[..1], "f");
+    }
+
+    #[test]
+    fn retain() {
+        let mut ne_foo_str = NonEmptyString::new("f_ö_💡".to_owned()).unwrap();
+
+        assert_eq!(ne_foo_str.retain(|c| c != '_'), Ok(()));
+        cmp(&ne_foo_str, "fö💡");
+
+        assert_eq!(ne_foo_str.retain(|c| c == '_'), Err(()));
+        cmp(&ne_foo_str, "fö💡");
+
+        assert_eq!(ne_foo_str.retain(|c| c.is_ascii()), Ok(()));
+        cmp(&ne_foo_str, "f");
+
+        let mut ne_foo_str = NonEmptyString::new("__f_ö_💡".to_owned()).unwrap();
+        let mut visited = String::new();
+        assert_eq!(
+            ne_foo_str.retain(|c| {
+                visited.push(c);
+                c != '_'
+            }),
+            Ok(())
+        );
+        cmp(&ne_foo_str, "fö💡");
+        // The predicate is called once per char, in order.
+        assert_eq!(visited, "__f_ö_💡");
+    }
+
+    #[test]
+    fn make_ascii_case() {
+        let mut ne_foo_str = NonEmptyString::new("fÖo💡".to_owned()).unwrap();
+
+        ne_foo_str.make_ascii_uppercase();
+        cmp(&ne_foo_str, "FÖO💡");
+
+        ne_foo_str.make_ascii_lowercase();
+        cmp(&ne_foo_str, "fÖo💡");
+    }
+
+    #[test]
+    fn capacity() {
+        let mut ne_foo_str = NonEmptyString::with_capacity(16, 'f');
+        cmp(&ne_foo_str, "f");
+        assert!(ne_foo_str.capacity() >= 16);
+
+        let capacity = ne_foo_str.capacity();
+        ne_foo_str.push_str("oo");
+        cmp(&ne_foo_str, "foo");
+        assert_eq!(ne_foo_str.capacity(), capacity);
+
+        let ne_foo_str = NonEmptyString::with_capacity(0, '💡');
+        cmp(&ne_foo_str, "💡");
+        assert!(ne_foo_str.capacity() >= 4);
+
+        let mut ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+        ne_foo_str.reserve(100);
+        assert!(ne_foo_str.capacity() >= 103);
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.shrink_to_fit();
+        assert!(ne_foo_str.capacity() >= 3);
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.reserve_exact(7);
+        assert!(ne_foo_str.capacity() >= 10);
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.reserve(100);
+        ne_foo_str.shrink_to(50);
+        assert!(ne_foo_str.capacity() >= 50);
+        cmp(&ne_foo_str, "foo");
+
+        ne_foo_str.shrink_to(0);
+        assert!(ne_foo_str.capacity() >= 3);
+        cmp(&ne_foo_str, "foo");
+    }
+
     #[cfg(debug_assertions)]
     #[test]
     #[should_panic(expected = "tried to create a non-empty string from an empty source")]
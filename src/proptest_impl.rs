@@ -0,0 +1,52 @@
+use {
+    crate::*,
+    alloc::string::String,
+    proptest::{
+        prelude::*,
+        string::{string_regex, Error},
+    },
+};
+
+/// Returns a [`Strategy`] which generates arbitrary [`NonEmptyString`]'s.
+///
+/// Empty strings are never generated.
+pub fn non_empty_string() -> impl Strategy<Value = NonEmptyString> {
+    any::<String>().prop_filter_map("string is empty", NonEmptyString::new)
+}
+
+/// Returns a [`Strategy`] which generates [`NonEmptyString`]'s matching the regular expression `pattern`.
+///
+/// Empty strings are never generated, even if the `pattern` matches an empty string -
+/// such values are rejected (so a `pattern` which only matches an empty string will fail the test).
+///
+/// Returns an error if the `pattern` is not a valid regular expression.
+#[allow(clippy::result_large_err)]
+pub fn non_empty_string_regex(
+    pattern: &str,
+) -> Result<impl Strategy<Value = NonEmptyString>, Error> {
+    Ok(string_regex(pattern)?.prop_filter_map("string is empty", NonEmptyString::new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn non_empty(s in non_empty_string()) {
+            prop_assert!(!s.as_str().is_empty());
+        }
+
+        #[test]
+        fn non_empty_regex(s in non_empty_string_regex("[a-c]{0,3}").unwrap()) {
+            prop_assert!(!s.as_str().is_empty());
+            prop_assert!(s.len_nonzero().get() <= 3);
+            prop_assert!(s.as_str().chars().all(|c| ('a'..='c').contains(&c)));
+        }
+    }
+
+    #[test]
+    fn invalid_regex() {
+        assert!(non_empty_string_regex("(").is_err());
+    }
+}
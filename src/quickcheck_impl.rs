@@ -0,0 +1,55 @@
+use {
+    crate::*,
+    alloc::{boxed::Box, string::String},
+    quickcheck::{Arbitrary, Gen},
+};
+
+/// Always generates a non-empty string -
+/// if the generated [`String`] is empty, an arbitrary char is appended to it.
+///
+/// Never shrinks to an empty string.
+impl Arbitrary for NonEmptyString {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut s = String::arbitrary(g);
+
+        if s.is_empty() {
+            s.push(char::arbitrary(g));
+        }
+
+        unsafe { NonEmptyString::new_unchecked(s) }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.inner().shrink().filter_map(NonEmptyString::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, quickcheck::quickcheck};
+
+    #[test]
+    fn arbitrary() {
+        fn non_empty(s: NonEmptyString) -> bool {
+            !s.as_str().is_empty()
+        }
+
+        quickcheck(non_empty as fn(NonEmptyString) -> bool);
+    }
+
+    #[test]
+    fn shrink() {
+        let mut g = Gen::new(16);
+
+        for _ in 0..64 {
+            let s = NonEmptyString::arbitrary(&mut g);
+
+            for s in s.shrink() {
+                assert!(!s.as_str().is_empty());
+            }
+        }
+
+        let f = NonEmptyString::new("f".to_owned()).unwrap();
+        assert!(f.shrink().all(|s| !s.as_str().is_empty()));
+    }
+}
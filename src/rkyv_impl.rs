@@ -0,0 +1,141 @@
+use {
+    crate::*,
+    core::{fmt, ops::Deref},
+    rkyv::{
+        bytecheck::CheckBytes,
+        rancor::{fail, Fallible, Source},
+        string::{ArchivedString, StringResolver},
+        Archive, Deserialize, Place, Portable, Serialize, SerializeUnsized,
+    },
+};
+
+/// An archived [`NonEmptyString`].
+///
+/// Has the same layout as an [`ArchivedString`], but is guaranteed to be non-empty.
+/// Validation (e.g. via [`rkyv::access`]) rejects empty archived strings.
+#[repr(transparent)]
+pub struct ArchivedNonEmptyString(ArchivedString);
+
+impl ArchivedNonEmptyString {
+    /// Returns the archived string as a [`NonEmptyStr`].
+    pub fn as_ne_str(&self) -> &NonEmptyStr {
+        unsafe { NonEmptyStr::new_unchecked(self.0.as_str()) }
+    }
+}
+
+unsafe impl Portable for ArchivedNonEmptyString {}
+
+/// Validates the inner [`ArchivedString`], then returns an [`EmptyStringError`] if it is empty.
+unsafe impl<C> CheckBytes<C> for ArchivedNonEmptyString
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+    ArchivedString: CheckBytes<C>,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        ArchivedString::check_bytes(value.cast(), context)?;
+
+        if (&*value).0.is_empty() {
+            fail!(EmptyStringError);
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for ArchivedNonEmptyString {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ne_str()
+    }
+}
+
+impl fmt::Debug for ArchivedNonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ArchivedNonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<NonEmptyString> for ArchivedNonEmptyString {
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for ArchivedNonEmptyString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ArchivedNonEmptyString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Archive for NonEmptyString {
+    type Archived = ArchivedNonEmptyString;
+    type Resolver = StringResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self.as_str(), resolver, unsafe { out.cast_unchecked() });
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for NonEmptyString
+where
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<NonEmptyString, D> for ArchivedNonEmptyString {
+    fn deserialize(&self, _: &mut D) -> Result<NonEmptyString, D::Error> {
+        Ok(NonEmptyString::from(self.as_ne_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, alloc::string::String, rkyv::rancor::Error};
+
+    #[test]
+    fn archive_roundtrip() {
+        for s in ["f", "foo", "a string long enough to not be stored inline"] {
+            let ne_string = NonEmptyString::new(s.to_owned()).unwrap();
+            let bytes = rkyv::to_bytes::<Error>(&ne_string).unwrap();
+
+            let archived = rkyv::access::<ArchivedNonEmptyString, Error>(&bytes).unwrap();
+            assert_eq!(archived, &ne_string);
+            assert_eq!(archived.as_ne_str(), s);
+            assert_eq!(archived.len_nonzero().get(), s.len());
+
+            let deserialized = rkyv::deserialize::<NonEmptyString, Error>(archived).unwrap();
+            assert_eq!(deserialized, ne_string);
+        }
+    }
+
+    #[test]
+    fn reject_empty() {
+        // An empty `String` has the same archived layout as a `NonEmptyString`.
+        let bytes = rkyv::to_bytes::<Error>(&String::new()).unwrap();
+        assert!(rkyv::access::<ArchivedNonEmptyString, Error>(&bytes).is_err());
+
+        let bytes = rkyv::to_bytes::<Error>(&String::from("foo")).unwrap();
+        assert_eq!(
+            rkyv::access::<ArchivedNonEmptyString, Error>(&bytes).unwrap(),
+            "foo"
+        );
+    }
+}
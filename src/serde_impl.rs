@@ -0,0 +1,83 @@
+use {
+    crate::*,
+    alloc::string::String,
+    serde::{
+        de::{Error, Unexpected},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+};
+
+impl Serialize for NonEmptyStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for NonEmptyString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NonEmptyString::new(String::deserialize(deserializer)?).ok_or_else(|| {
+            D::Error::invalid_value(
+                Unexpected::Str(""),
+                &"a string which is required to be non-empty",
+            )
+        })
+    }
+}
+
+/// Borrows the string from the deserializer's input (i.e. via `#[serde(borrow)]`) without allocating.
+///
+/// Fails if the deserializer can't provide a borrowed string (e.g. if it contains escape sequences in JSON).
+impl<'de: 'a, 'a> Deserialize<'de> for &'a NonEmptyStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NonEmptyStr::new(<&'a str>::deserialize(deserializer)?).ok_or_else(|| {
+            D::Error::invalid_value(
+                Unexpected::Str(""),
+                &"a string which is required to be non-empty",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_deserialize() {
+        let ne_foo = NonEmptyStr::new("foo").unwrap();
+        assert_eq!(serde_json::to_string(ne_foo).unwrap(), r#""foo""#);
+
+        let ne_foo_str = NonEmptyString::new("foo".to_owned()).unwrap();
+        assert_eq!(serde_json::to_string(&ne_foo_str).unwrap(), r#""foo""#);
+
+        let ne_foo_str: NonEmptyString = serde_json::from_str(r#""foo""#).unwrap();
+        assert_eq!(ne_foo_str, "foo");
+
+        let err = serde_json::from_str::<NonEmptyString>(r#""""#).unwrap_err();
+        assert!(err.to_string().contains("non-empty"));
+    }
+
+    #[test]
+    fn deserialize_borrowed() {
+        let json = String::from(r#""foo""#);
+        let ne_foo: &NonEmptyStr = serde_json::from_str(&json).unwrap();
+        assert_eq!(ne_foo, "foo");
+        // Borrowed from the input.
+        assert_eq!(ne_foo.as_str().as_ptr(), json[1..].as_ptr());
+
+        let err = serde_json::from_str::<&NonEmptyStr>(r#""""#).unwrap_err();
+        assert!(err.to_string().contains("non-empty"));
+
+        // Can't borrow escaped strings.
+        let err = serde_json::from_str::<&NonEmptyStr>(r#""f\noo""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"invalid type: string "f\noo", expected a borrowed string"#));
+    }
+}